@@ -0,0 +1,503 @@
+//! Derive macros for the [`cc-traits`](https://crates.io/crates/cc-traits) crate.
+//!
+//! Each macro implements the trait of the same name by forwarding it to
+//! a field of the annotated structure, designated with the
+//! `#[cc_traits(delegate = "field")]` attribute.
+//! For tuple structures, the field is designated by its index
+//! (e.g. `#[cc_traits(delegate = "0")]`).
+//! The attribute can be omitted if the structure has only one field.
+//!
+//! The implementations are only available when the delegate field type implements
+//! the trait, and the generic associated types (such as `ItemRef<'a>`) are those of
+//! the delegate field.
+//!
+//! ## Example
+//!
+//! ```
+//! use cc_traits::{Collection, CollectionRef, Get, Len, SimpleCollectionRef};
+//!
+//! #[derive(Collection, CollectionRef, SimpleCollectionRef, Len, Get)]
+//! #[cc_traits(delegate = "items")]
+//! pub struct MyVec<T> {
+//!     items: Vec<T>,
+//!     name: String,
+//! }
+//!
+//! fn first<C: Get<usize>>(c: &C) -> Option<C::ItemRef<'_>> {
+//!     c.get(0)
+//! }
+//!
+//! let v = MyVec {
+//!     items: vec![1, 2, 3],
+//!     name: "numbers".to_string(),
+//! };
+//!
+//! assert_eq!(v.len(), 3);
+//! assert_eq!(first(&v).map(|x| *x), Some(1));
+//! ```
+//!
+//! Note that `Front`, `Back`, `FrontMut` and `BackMut` are not derivable since they
+//! are already implemented for every collection implementing `Get<usize>` and `Len`
+//! (resp. `GetMut<usize>` and `Len`).
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Ident, Index, LitStr, Member, Type};
+
+/// Field to which the traits are delegated.
+struct Delegate {
+	member: Member,
+	ty: Type,
+}
+
+fn member(i: usize, ident: &Option<Ident>) -> Member {
+	match ident {
+		Some(ident) => Member::Named(ident.clone()),
+		None => Member::Unnamed(Index::from(i)),
+	}
+}
+
+fn delegate(input: &DeriveInput) -> syn::Result<Delegate> {
+	let fields = match &input.data {
+		Data::Struct(s) => &s.fields,
+		_ => {
+			return Err(syn::Error::new(
+				input.ident.span(),
+				"collection traits can only be derived on structures",
+			))
+		}
+	};
+
+	let mut name: Option<LitStr> = None;
+	for attr in &input.attrs {
+		if attr.path().is_ident("cc_traits") {
+			attr.parse_nested_meta(|meta| {
+				if meta.path.is_ident("delegate") {
+					name = Some(meta.value()?.parse()?);
+					Ok(())
+				} else {
+					Err(meta.error("unsupported `cc_traits` attribute"))
+				}
+			})?
+		}
+	}
+
+	match name {
+		Some(name) => {
+			let value = name.value();
+			for (i, field) in fields.iter().enumerate() {
+				let matches = match &field.ident {
+					Some(ident) => *ident == value,
+					None => i.to_string() == value,
+				};
+
+				if matches {
+					return Ok(Delegate {
+						member: member(i, &field.ident),
+						ty: field.ty.clone(),
+					});
+				}
+			}
+
+			Err(syn::Error::new(
+				name.span(),
+				format!("no field named `{}`", value),
+			))
+		}
+		None => {
+			let mut fields = fields.iter();
+			match (fields.next(), fields.next()) {
+				(Some(field), None) => Ok(Delegate {
+					member: member(0, &field.ident),
+					ty: field.ty.clone(),
+				}),
+				_ => Err(syn::Error::new(
+					input.ident.span(),
+					"missing `#[cc_traits(delegate = \"field\")]` attribute",
+				)),
+			}
+		}
+	}
+}
+
+/// Expands into an implementation of the trait returned by `f`,
+/// forwarding to the delegate field.
+///
+/// If `generic` is true, the implementation is made generic
+/// over an additional `__Q` type parameter.
+fn expand<F>(input: &DeriveInput, generic: bool, f: F) -> syn::Result<TokenStream2>
+where
+	F: FnOnce(&Type, &Member) -> (TokenStream2, TokenStream2),
+{
+	let Delegate { member, ty } = delegate(input)?;
+	let (tr, body) = f(&ty, &member);
+
+	let mut generics = input.generics.clone();
+	if generic {
+		let q = Ident::new("__Q", Span::call_site());
+		generics.params.push(parse_quote!(#q));
+	}
+	generics
+		.make_where_clause()
+		.predicates
+		.push(parse_quote!(#ty: #tr));
+
+	let ident = &input.ident;
+	let (impl_generics, _, where_clause) = generics.split_for_impl();
+	let (_, ty_generics, _) = input.generics.split_for_impl();
+
+	Ok(quote! {
+		impl #impl_generics #tr for #ident #ty_generics #where_clause {
+			#body
+		}
+	})
+}
+
+macro_rules! derives {
+	($($(#[$meta:meta])* $name:ident, $fn_name:ident, $generic:literal, |$ty:ident, $member:ident| $f:expr;)*) => {
+		$(
+			$(#[$meta])*
+			#[proc_macro_derive($name, attributes(cc_traits))]
+			pub fn $fn_name(input: TokenStream) -> TokenStream {
+				let input = parse_macro_input!(input as DeriveInput);
+				#[allow(unused_variables)]
+				match expand(&input, $generic, |$ty, $member| $f) {
+					Ok(tokens) => tokens.into(),
+					Err(e) => e.to_compile_error().into(),
+				}
+			}
+		)*
+	};
+}
+
+derives! {
+	/// Derives the `Collection` trait.
+	Collection, derive_collection, false, |ty, member| (
+		quote!(::cc_traits::Collection),
+		quote! {
+			type Item = <#ty as ::cc_traits::Collection>::Item;
+		},
+	);
+
+	/// Derives the `CollectionRef` trait.
+	CollectionRef, derive_collection_ref, false, |ty, member| (
+		quote!(::cc_traits::CollectionRef),
+		quote! {
+			type ItemRef<'a> = <#ty as ::cc_traits::CollectionRef>::ItemRef<'a> where Self: 'a;
+
+			#[inline(always)]
+			fn upcast_item_ref<'short, 'long: 'short>(r: Self::ItemRef<'long>) -> Self::ItemRef<'short>
+			where
+				Self: 'long,
+			{
+				<#ty as ::cc_traits::CollectionRef>::upcast_item_ref(r)
+			}
+		},
+	);
+
+	/// Derives the `CollectionMut` trait.
+	CollectionMut, derive_collection_mut, false, |ty, member| (
+		quote!(::cc_traits::CollectionMut),
+		quote! {
+			type ItemMut<'a> = <#ty as ::cc_traits::CollectionMut>::ItemMut<'a> where Self: 'a;
+
+			#[inline(always)]
+			fn upcast_item_mut<'short, 'long: 'short>(r: Self::ItemMut<'long>) -> Self::ItemMut<'short>
+			where
+				Self: 'long,
+			{
+				<#ty as ::cc_traits::CollectionMut>::upcast_item_mut(r)
+			}
+		},
+	);
+
+	/// Derives the `SimpleCollectionRef` trait.
+	SimpleCollectionRef, derive_simple_collection_ref, false, |ty, member| (
+		quote!(::cc_traits::SimpleCollectionRef),
+		quote! {
+			#[inline(always)]
+			fn into_ref<'r>(r: Self::ItemRef<'r>) -> &'r Self::Item
+			where
+				Self: 'r,
+			{
+				<#ty as ::cc_traits::SimpleCollectionRef>::into_ref(r)
+			}
+		},
+	);
+
+	/// Derives the `SimpleCollectionMut` trait.
+	SimpleCollectionMut, derive_simple_collection_mut, false, |ty, member| (
+		quote!(::cc_traits::SimpleCollectionMut),
+		quote! {
+			#[inline(always)]
+			fn into_mut<'r>(r: Self::ItemMut<'r>) -> &'r mut Self::Item
+			where
+				Self: 'r,
+			{
+				<#ty as ::cc_traits::SimpleCollectionMut>::into_mut(r)
+			}
+		},
+	);
+
+	/// Derives the `Keyed` trait.
+	Keyed, derive_keyed, false, |ty, member| (
+		quote!(::cc_traits::Keyed),
+		quote! {
+			type Key = <#ty as ::cc_traits::Keyed>::Key;
+		},
+	);
+
+	/// Derives the `KeyedRef` trait.
+	KeyedRef, derive_keyed_ref, false, |ty, member| (
+		quote!(::cc_traits::KeyedRef),
+		quote! {
+			type KeyRef<'a> = <#ty as ::cc_traits::KeyedRef>::KeyRef<'a> where Self: 'a;
+
+			#[inline(always)]
+			fn upcast_key_ref<'short, 'long: 'short>(r: Self::KeyRef<'long>) -> Self::KeyRef<'short>
+			where
+				Self: 'long,
+			{
+				<#ty as ::cc_traits::KeyedRef>::upcast_key_ref(r)
+			}
+		},
+	);
+
+	/// Derives the `SimpleKeyedRef` trait.
+	SimpleKeyedRef, derive_simple_keyed_ref, false, |ty, member| (
+		quote!(::cc_traits::SimpleKeyedRef),
+		quote! {
+			#[inline(always)]
+			fn into_ref<'r>(r: Self::KeyRef<'r>) -> &'r Self::Key
+			where
+				Self: 'r,
+			{
+				<#ty as ::cc_traits::SimpleKeyedRef>::into_ref(r)
+			}
+		},
+	);
+
+	/// Derives the `Len` trait.
+	Len, derive_len, false, |ty, member| (
+		quote!(::cc_traits::Len),
+		quote! {
+			#[inline(always)]
+			fn len(&self) -> usize {
+				<#ty as ::cc_traits::Len>::len(&self.#member)
+			}
+
+			#[inline(always)]
+			fn is_empty(&self) -> bool {
+				<#ty as ::cc_traits::Len>::is_empty(&self.#member)
+			}
+		},
+	);
+
+	/// Derives the `Capacity` trait.
+	Capacity, derive_capacity, false, |ty, member| (
+		quote!(::cc_traits::Capacity),
+		quote! {
+			#[inline(always)]
+			fn capacity(&self) -> usize {
+				<#ty as ::cc_traits::Capacity>::capacity(&self.#member)
+			}
+		},
+	);
+
+	/// Derives the `Reserve` trait.
+	Reserve, derive_reserve, false, |ty, member| (
+		quote!(::cc_traits::Reserve),
+		quote! {
+			#[inline(always)]
+			fn reserve(&mut self, additional: usize) {
+				<#ty as ::cc_traits::Reserve>::reserve(&mut self.#member, additional)
+			}
+		},
+	);
+
+	/// Derives the `Get` trait.
+	Get, derive_get, true, |ty, member| (
+		quote!(::cc_traits::Get<__Q>),
+		quote! {
+			#[inline(always)]
+			fn get(&self, key: __Q) -> Option<Self::ItemRef<'_>> {
+				<#ty as ::cc_traits::Get<__Q>>::get(&self.#member, key)
+			}
+
+			#[inline(always)]
+			fn contains(&self, key: __Q) -> bool {
+				<#ty as ::cc_traits::Get<__Q>>::contains(&self.#member, key)
+			}
+		},
+	);
+
+	/// Derives the `GetMut` trait.
+	GetMut, derive_get_mut, true, |ty, member| (
+		quote!(::cc_traits::GetMut<__Q>),
+		quote! {
+			#[inline(always)]
+			fn get_mut(&mut self, key: __Q) -> Option<Self::ItemMut<'_>> {
+				<#ty as ::cc_traits::GetMut<__Q>>::get_mut(&mut self.#member, key)
+			}
+		},
+	);
+
+	/// Derives the `GetKeyValue` trait.
+	GetKeyValue, derive_get_key_value, true, |ty, member| (
+		quote!(::cc_traits::GetKeyValue<__Q>),
+		quote! {
+			#[inline(always)]
+			fn get_key_value(&self, key: __Q) -> Option<(Self::KeyRef<'_>, Self::ItemRef<'_>)> {
+				<#ty as ::cc_traits::GetKeyValue<__Q>>::get_key_value(&self.#member, key)
+			}
+		},
+	);
+
+	/// Derives the `Insert` trait.
+	Insert, derive_insert, false, |ty, member| (
+		quote!(::cc_traits::Insert),
+		quote! {
+			type Output = <#ty as ::cc_traits::Insert>::Output;
+
+			#[inline(always)]
+			fn insert(&mut self, element: Self::Item) -> Self::Output {
+				<#ty as ::cc_traits::Insert>::insert(&mut self.#member, element)
+			}
+		},
+	);
+
+	/// Derives the `MapInsert` trait.
+	MapInsert, derive_map_insert, true, |ty, member| (
+		quote!(::cc_traits::MapInsert<__Q>),
+		quote! {
+			type Output = <#ty as ::cc_traits::MapInsert<__Q>>::Output;
+
+			#[inline(always)]
+			fn insert(&mut self, key: __Q, value: Self::Item) -> Self::Output {
+				<#ty as ::cc_traits::MapInsert<__Q>>::insert(&mut self.#member, key, value)
+			}
+		},
+	);
+
+	/// Derives the `PushFront` trait.
+	PushFront, derive_push_front, false, |ty, member| (
+		quote!(::cc_traits::PushFront),
+		quote! {
+			type Output = <#ty as ::cc_traits::PushFront>::Output;
+
+			#[inline(always)]
+			fn push_front(&mut self, element: Self::Item) -> Self::Output {
+				<#ty as ::cc_traits::PushFront>::push_front(&mut self.#member, element)
+			}
+		},
+	);
+
+	/// Derives the `PushBack` trait.
+	PushBack, derive_push_back, false, |ty, member| (
+		quote!(::cc_traits::PushBack),
+		quote! {
+			type Output = <#ty as ::cc_traits::PushBack>::Output;
+
+			#[inline(always)]
+			fn push_back(&mut self, element: Self::Item) -> Self::Output {
+				<#ty as ::cc_traits::PushBack>::push_back(&mut self.#member, element)
+			}
+		},
+	);
+
+	/// Derives the `Remove` trait.
+	Remove, derive_remove, true, |ty, member| (
+		quote!(::cc_traits::Remove<__Q>),
+		quote! {
+			#[inline(always)]
+			fn remove(&mut self, key: __Q) -> Option<Self::Item> {
+				<#ty as ::cc_traits::Remove<__Q>>::remove(&mut self.#member, key)
+			}
+		},
+	);
+
+	/// Derives the `PopFront` trait.
+	PopFront, derive_pop_front, false, |ty, member| (
+		quote!(::cc_traits::PopFront),
+		quote! {
+			#[inline(always)]
+			fn pop_front(&mut self) -> Option<Self::Item> {
+				<#ty as ::cc_traits::PopFront>::pop_front(&mut self.#member)
+			}
+		},
+	);
+
+	/// Derives the `PopBack` trait.
+	PopBack, derive_pop_back, false, |ty, member| (
+		quote!(::cc_traits::PopBack),
+		quote! {
+			#[inline(always)]
+			fn pop_back(&mut self) -> Option<Self::Item> {
+				<#ty as ::cc_traits::PopBack>::pop_back(&mut self.#member)
+			}
+		},
+	);
+
+	/// Derives the `Clear` trait.
+	Clear, derive_clear, false, |ty, member| (
+		quote!(::cc_traits::Clear),
+		quote! {
+			#[inline(always)]
+			fn clear(&mut self) {
+				<#ty as ::cc_traits::Clear>::clear(&mut self.#member)
+			}
+		},
+	);
+
+	/// Derives the `Iter` trait.
+	Iter, derive_iter, false, |ty, member| (
+		quote!(::cc_traits::Iter),
+		quote! {
+			type Iter<'a> = <#ty as ::cc_traits::Iter>::Iter<'a> where Self: 'a;
+
+			#[inline(always)]
+			fn iter(&self) -> Self::Iter<'_> {
+				<#ty as ::cc_traits::Iter>::iter(&self.#member)
+			}
+		},
+	);
+
+	/// Derives the `IterMut` trait.
+	IterMut, derive_iter_mut, false, |ty, member| (
+		quote!(::cc_traits::IterMut),
+		quote! {
+			type IterMut<'a> = <#ty as ::cc_traits::IterMut>::IterMut<'a> where Self: 'a;
+
+			#[inline(always)]
+			fn iter_mut(&mut self) -> Self::IterMut<'_> {
+				<#ty as ::cc_traits::IterMut>::iter_mut(&mut self.#member)
+			}
+		},
+	);
+
+	/// Derives the `MapIter` trait.
+	MapIter, derive_map_iter, false, |ty, member| (
+		quote!(::cc_traits::MapIter),
+		quote! {
+			type Iter<'a> = <#ty as ::cc_traits::MapIter>::Iter<'a> where Self: 'a;
+
+			#[inline(always)]
+			fn iter(&self) -> Self::Iter<'_> {
+				<#ty as ::cc_traits::MapIter>::iter(&self.#member)
+			}
+		},
+	);
+
+	/// Derives the `MapIterMut` trait.
+	MapIterMut, derive_map_iter_mut, false, |ty, member| (
+		quote!(::cc_traits::MapIterMut),
+		quote! {
+			type IterMut<'a> = <#ty as ::cc_traits::MapIterMut>::IterMut<'a> where Self: 'a;
+
+			#[inline(always)]
+			fn iter_mut(&mut self) -> Self::IterMut<'_> {
+				<#ty as ::cc_traits::MapIterMut>::iter_mut(&mut self.#member)
+			}
+		},
+	);
+}
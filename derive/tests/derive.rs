@@ -0,0 +1,160 @@
+use cc_traits::{
+	BackMut, Clear, Collection, CollectionMut, CollectionRef, Get, GetKeyValue, GetMut, Insert,
+	Iter, IterMut, Keyed, KeyedRef, Len, MapInsert, MapIter, MapIterMut, PopBack, PushBack, Remove,
+	SimpleCollectionMut, SimpleCollectionRef, SimpleKeyedRef,
+};
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(
+	Default,
+	Collection,
+	CollectionRef,
+	CollectionMut,
+	SimpleCollectionRef,
+	SimpleCollectionMut,
+	Len,
+	Get,
+	GetMut,
+	PushBack,
+	PopBack,
+	Clear,
+	Iter,
+	IterMut,
+)]
+#[cc_traits(delegate = "items")]
+struct Stack<T> {
+	items: Vec<T>,
+	pushes: usize,
+}
+
+#[derive(
+	Default,
+	Collection,
+	CollectionRef,
+	CollectionMut,
+	Keyed,
+	KeyedRef,
+	SimpleKeyedRef,
+	Len,
+	Get,
+	GetMut,
+	GetKeyValue,
+	MapInsert,
+	Remove,
+	MapIter,
+	MapIterMut,
+)]
+struct Map<K: Ord, V>(BTreeMap<K, V>);
+
+#[derive(Default, Collection, CollectionRef, Len, Get, Insert, Remove, Iter)]
+#[cc_traits(delegate = "0")]
+struct Set<T: Ord>(BTreeSet<T>, ());
+
+fn stack_ops<S>(stack: &mut S)
+where
+	S: Collection<Item = u32> + Len + BackMut + PushBack + PopBack + Clear + Iter + IterMut,
+	S: SimpleCollectionRef + SimpleCollectionMut,
+{
+	stack.push_back(1);
+	stack.push_back(2);
+	stack.push_back(3);
+	assert_eq!(stack.len(), 3);
+
+	*S::into_mut(stack.back_mut().unwrap()) = 4;
+	for x in stack.iter_mut() {
+		*S::into_mut(x) *= 10
+	}
+
+	let items: Vec<u32> = stack.iter().map(|x| *S::into_ref(x)).collect();
+	assert_eq!(items, [10, 20, 40]);
+
+	assert_eq!(stack.pop_back(), Some(40));
+	stack.clear();
+	assert!(stack.is_empty());
+}
+
+fn map_ops<M>(map: &mut M)
+where
+	M: Keyed<Key = &'static str> + Collection<Item = u32> + Len + MapIter,
+	M: MapInsert<&'static str, Output = Option<u32>> + MapIterMut,
+	M: for<'a> Get<&'a &'static str> + for<'a> GetKeyValue<&'a &'static str>,
+	M: for<'a> GetMut<&'a &'static str> + for<'a> Remove<&'a &'static str>,
+{
+	assert_eq!(map.insert("a", 1), None);
+	assert_eq!(map.insert("b", 2), None);
+	assert_eq!(map.insert("a", 3), Some(1));
+	assert_eq!(map.len(), 2);
+
+	*map.get_mut(&"b").unwrap() += 1;
+	for (_, mut v) in MapIterMut::iter_mut(map) {
+		*v += 1
+	}
+
+	assert_eq!(map.get(&"a").map(|v| *v), Some(4));
+	{
+		let (k, v) = map.get_key_value(&"b").unwrap();
+		assert_eq!((*k, *v), ("b", 4));
+	}
+
+	let keys: Vec<&str> = MapIter::iter(map).map(|(k, _)| *k).collect();
+	assert_eq!(keys, ["a", "b"]);
+
+	assert_eq!(map.remove(&"a"), Some(4));
+	assert!(!map.contains(&"a"));
+}
+
+#[test]
+fn derive_stack() {
+	let mut stack: Stack<u32> = Stack::default();
+	stack_ops(&mut stack);
+	assert_eq!(stack.pushes, 0)
+}
+
+#[test]
+fn derive_map() {
+	let mut map: Map<&'static str, u32> = Map::default();
+	map_ops(&mut map);
+	assert_eq!(map.0.len(), 1)
+}
+
+#[test]
+fn derive_set() {
+	let mut set: Set<u32> = Set::default();
+	assert!(set.insert(2));
+	assert!(set.insert(1));
+	assert!(!set.insert(2));
+	assert!(set.contains(&1));
+
+	let items: Vec<u32> = set.iter().copied().collect();
+	assert_eq!(items, [1, 2]);
+
+	assert_eq!(set.remove(&1), Some(1));
+	assert_eq!(set.len(), 1);
+	assert_eq!(set.1, ())
+}
+
+fn item_ref<'a, C: CollectionRef>(r: C::ItemRef<'a>) -> C::ItemRef<'a> {
+	C::upcast_item_ref(r)
+}
+
+fn key_ref<'a, C: KeyedRef>(r: C::KeyRef<'a>) -> C::KeyRef<'a> {
+	C::upcast_key_ref(r)
+}
+
+fn item_mut<'a, C: CollectionMut>(r: C::ItemMut<'a>) -> C::ItemMut<'a> {
+	C::upcast_item_mut(r)
+}
+
+#[test]
+fn derive_upcast() {
+	let mut stack: Stack<u32> = Stack::default();
+	stack.push_back(1);
+	assert_eq!(*item_ref::<Stack<u32>>(stack.get(0).unwrap()), 1);
+	*item_mut::<Stack<u32>>(stack.get_mut(0).unwrap()) = 2;
+	assert_eq!(stack.items, [2]);
+
+	let mut map: Map<&'static str, u32> = Map::default();
+	MapInsert::insert(&mut map, "a", 1);
+	let (k, _) = map.get_key_value(&"a").unwrap();
+	assert_eq!(*key_ref::<Map<&'static str, u32>>(k), "a")
+}
@@ -1,5 +1,5 @@
 use crate::*;
-use std::ops::{Index, IndexMut};
+use std::ops::{Bound, Index, IndexMut};
 
 /// Collection with mutable capacity.
 pub trait CapacityMut = Capacity + Reserve;
@@ -30,7 +30,8 @@ pub trait Vec<T> = Stack<T> + Index<usize, Output = T>;
 ///
 /// This trait alias describes the mutables operations on a Vec.
 /// See [`Vec`] for more details.
-pub trait VecMut<T> = Vec<T> + StackMut<T> + IndexMut<usize>;
+pub trait VecMut<T> =
+	Vec<T> + StackMut<T> + IndexMut<usize> + InsertAt + SwapRemove + Truncate;
 
 /// Immutable double-ended queue.
 ///
@@ -67,6 +68,38 @@ pub trait Set<T> = Collection<Item = T> + Len + for<'a> Get<&'a T>;
 /// Mutable set data structure.
 pub trait SetMut<T> = Set<T> + Insert<Output = bool> + for<'a> Remove<&'a T>;
 
+/// Immutable set data structure supporting set algebra operations.
+///
+/// Provides lazy iterators over the union, intersection, difference and
+/// symmetric difference of two sets, and inclusion tests between sets.
+pub trait SetAlgebra<T> =
+	Set<T> + Union + Intersection + Difference + SymmetricDifference + IsSubset + IsDisjoint;
+
+/// Mutable set data structure supporting set algebra operations.
+///
+/// In addition to [`SetAlgebra`], the set can be combined in place
+/// with another set.
+pub trait SetAlgebraMut<T> =
+	SetAlgebra<T> + SetMut<T> + UnionWith + RetainIn + RemoveIn + SymmetricDifferenceWith;
+
+/// Immutable ordered set data structure.
+///
+/// An ordered set is a [`Set`] whose items are sorted,
+/// providing range queries and predecessor/successor lookups.
+pub trait OrderedSet<T> = Set<T>
+	+ Ordered
+	+ Iter
+	+ First
+	+ Last
+	+ for<'a> Floor<&'a T>
+	+ for<'a> Ceiling<&'a T>
+	+ for<'a> Range<T, (Bound<&'a T>, Bound<&'a T>)>;
+
+/// Mutable ordered set data structure.
+///
+/// See [`OrderedSet`] for more details.
+pub trait OrderedSetMut<T> = OrderedSet<T> + SetMut<T> + PopFirst + PopLast;
+
 /// Imutable map data structure.
 ///
 /// A map is an unordered collection storing key-value pairs, indexed by the key.
@@ -74,8 +107,43 @@ pub trait Map<K, V> =
 	Keyed<Key = K, Item = V> + Len + for<'a> Get<&'a K> + for<'a> GetKeyValue<&'a K>;
 
 /// Mutable map data structure.
-pub trait MapMut<K, V> =
-	Map<K, V> + for<'a> GetMut<&'a K> + MapInsert<K, Output = Option<V>> + for<'a> Remove<&'a K>;
+pub trait MapMut<K, V> = Map<K, V>
+	+ for<'a> GetMut<&'a K>
+	+ MapInsert<K, Output = Option<V>>
+	+ for<'a> Remove<&'a K>
+	+ for<'a> RemoveEntry<&'a K>;
+
+/// Immutable ordered map data structure.
+///
+/// An ordered map is a [`Map`] whose keys are sorted,
+/// providing range queries and predecessor/successor lookups.
+pub trait OrderedMap<K, V> = Map<K, V>
+	+ Ordered
+	+ MapIter
+	+ FirstKeyValue
+	+ LastKeyValue
+	+ for<'a> FloorKeyValue<&'a K>
+	+ for<'a> CeilingKeyValue<&'a K>
+	+ for<'a> MapRange<K, (Bound<&'a K>, Bound<&'a K>)>;
+
+/// Mutable ordered map data structure.
+///
+/// See [`OrderedMap`] for more details.
+pub trait OrderedMapMut<K, V> = OrderedMap<K, V> + MapMut<K, V> + PopFirstKeyValue + PopLastKeyValue;
+
+/// Immutable priority queue data structure.
+///
+/// A priority queue provides two main operations:
+///   - [`PushPriority::push`], which adds an element to the queue, and
+///   - [`PopMax::pop_max`], which removes the greatest element of the queue.
+pub trait PriorityQueue<T> = Collection<Item = T> + Len + Peek;
+
+/// Mutable priority queue data structure.
+///
+/// Every pushed item is kept in the queue, even if an equal item is
+/// already queued. Deduplicating queues (see [`PushPriority`]) do not
+/// implement this trait.
+pub trait PriorityQueueMut<T> = PriorityQueue<T> + PushPriority<Output = ()> + PopMax;
 
 /// Imutable slab data structure.
 ///
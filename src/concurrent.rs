@@ -0,0 +1,133 @@
+//! Concurrent collection traits.
+//!
+//! The mutating traits of this crate take `&mut self`, which rules out
+//! collections shared between threads. The traits of this module mutate
+//! the collection through a shared reference instead, and return
+//! guards keeping the referenced items alive.
+//!
+//! They are implemented for `dashmap` and `crossbeam-queue` collections
+//! when the corresponding features are enabled, and for any collection
+//! wrapped in a `Mutex` or an `RwLock` when the `std` feature is enabled.
+//! Lock-based implementations panic if the lock is poisoned.
+//!
+//! ```
+//! use cc_traits::concurrent::ConcurrentInsert;
+//!
+//! fn register<S>(names: &S, name: &str) -> bool
+//! where
+//!     S: ConcurrentInsert<Item = String, Output = bool>,
+//! {
+//!     names.insert(name.to_string())
+//! }
+//!
+//! #[cfg(feature = "std")]
+//! {
+//!     let names = std::sync::Mutex::new(std::collections::HashSet::new());
+//!     assert!(register(&names, "alice"));
+//!     assert!(!register(&names, "alice"));
+//! }
+//! ```
+use crate::Collection;
+use core::ops::Deref;
+
+/// Concurrent collection with items that can be referenced by key.
+pub trait ConcurrentGet<T>: Collection {
+	/// Guard giving access to an item of the collection.
+	///
+	/// The item is guaranteed to stay in the collection while the guard
+	/// is alive. Depending on the collection, holding this guard may
+	/// block other threads from modifying the collection.
+	type ItemRef<'a>: Deref<Target = Self::Item>
+	where
+		Self: 'a;
+
+	/// Returns a guard to the item stored behind the given key (if any).
+	fn get(&self, key: T) -> Option<Self::ItemRef<'_>>;
+
+	/// Checks if the collection contains an item behind the given key.
+	fn contains(&self, key: T) -> bool {
+		self.get(key).is_some()
+	}
+}
+
+/// Concurrent collection where new items can be inserted.
+pub trait ConcurrentInsert: Collection {
+	/// The output of the insertion function.
+	type Output;
+
+	/// Insert a new item in the collection.
+	fn insert(&self, element: Self::Item) -> Self::Output;
+}
+
+/// Concurrent collection where new key-value pairs can be inserted.
+pub trait ConcurrentMapInsert<K>: Collection {
+	/// The output of the insertion function.
+	type Output;
+
+	/// Insert a new key-value pair in the collection.
+	fn insert(&self, key: K, value: Self::Item) -> Self::Output;
+}
+
+/// Concurrent collection where items can be removed from.
+pub trait ConcurrentRemove<T>: Collection {
+	/// Remove the item stored behind the given key and return it (if any).
+	fn remove(&self, key: T) -> Option<Self::Item>;
+}
+
+/// Concurrent queue where items can be added.
+pub trait ConcurrentPush: Collection {
+	/// The output of the push function.
+	type Output;
+
+	/// Add the given item at the back of the queue.
+	fn push(&self, element: Self::Item) -> Self::Output;
+}
+
+/// Concurrent queue where items can be removed.
+pub trait ConcurrentPop: Collection {
+	/// Remove the front item of the queue and return it (if any).
+	fn pop(&self) -> Option<Self::Item>;
+}
+
+/// Guard to an item of a collection protected by a lock.
+///
+/// Holds the lock guard `G` along with the key `T` of the item, which is
+/// looked up again on each dereference. Since the lock is held, the item
+/// cannot be removed in the meantime.
+#[cfg(feature = "std")]
+pub struct LockedItemRef<'a, C: ?Sized, G, T> {
+	guard: G,
+	key: T,
+	collection: core::marker::PhantomData<&'a C>,
+}
+
+#[cfg(feature = "std")]
+impl<'a, C: ?Sized, G, T> LockedItemRef<'a, C, G, T> {
+	#[inline(always)]
+	pub(crate) fn new(guard: G, key: T) -> Self {
+		Self {
+			guard,
+			key,
+			collection: core::marker::PhantomData,
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl<'a, C, G, T> Deref for LockedItemRef<'a, C, G, T>
+where
+	C: crate::SimpleCollectionRef + crate::Get<T> + ?Sized,
+	G: Deref<Target = C>,
+	T: Clone,
+{
+	type Target = C::Item;
+
+	#[inline(always)]
+	fn deref(&self) -> &C::Item {
+		C::into_ref(
+			self.guard
+				.get(self.key.clone())
+				.expect("item removed while the lock is held"),
+		)
+	}
+}
@@ -0,0 +1,455 @@
+use crate::{Collection, Get, Len};
+use core::ops::Deref;
+
+/// Cursor over the items of a sequence.
+///
+/// A cursor points either to an item of the sequence, or to a "ghost"
+/// non-item placed between the back and the front of the sequence.
+/// Moving past the back (or the front) of the sequence moves the cursor
+/// on the ghost non-item, and moving again wraps around the sequence.
+pub trait Cursor<'a> {
+	/// Type of the sequence's items.
+	type Item;
+
+	/// Type of references to the sequence's items.
+	type ItemRef: Deref<Target = Self::Item>;
+
+	/// Returns the index of the current item,
+	/// or `None` if the cursor points to the ghost non-item.
+	fn index(&self) -> Option<usize>;
+
+	/// Moves the cursor to the next item.
+	fn move_next(&mut self);
+
+	/// Moves the cursor to the previous item.
+	fn move_prev(&mut self);
+
+	/// Returns a reference to the current item,
+	/// or `None` if the cursor points to the ghost non-item.
+	fn current(&self) -> Option<Self::ItemRef>;
+}
+
+/// Cursor over the items of a sequence, allowing the sequence to be edited.
+///
+/// See [`Cursor`] for more details.
+pub trait CursorMut<'a> {
+	/// Type of the sequence's items.
+	type Item;
+
+	/// Type of the sequence returned by [`CursorMut::split_after`].
+	type Split;
+
+	/// Returns the index of the current item,
+	/// or `None` if the cursor points to the ghost non-item.
+	fn index(&self) -> Option<usize>;
+
+	/// Moves the cursor to the next item.
+	fn move_next(&mut self);
+
+	/// Moves the cursor to the previous item.
+	fn move_prev(&mut self);
+
+	/// Returns a mutable reference to the current item,
+	/// or `None` if the cursor points to the ghost non-item.
+	fn current(&mut self) -> Option<&mut Self::Item>;
+
+	/// Inserts a new item before the current one.
+	///
+	/// If the cursor points to the ghost non-item,
+	/// the new item is inserted at the back of the sequence.
+	fn insert_before(&mut self, item: Self::Item);
+
+	/// Inserts a new item after the current one.
+	///
+	/// If the cursor points to the ghost non-item,
+	/// the new item is inserted at the front of the sequence.
+	fn insert_after(&mut self, item: Self::Item);
+
+	/// Removes the current item and returns it.
+	///
+	/// The cursor is moved to the next item.
+	/// If the cursor points to the ghost non-item, nothing is removed and `None` is returned.
+	fn remove_current(&mut self) -> Option<Self::Item>;
+
+	/// Splits the sequence after the current item,
+	/// and returns a new sequence with all the following items.
+	///
+	/// If the cursor points to the ghost non-item,
+	/// all the items of the sequence are moved to the returned sequence.
+	fn split_after(&mut self) -> Self::Split;
+}
+
+/// Sequence that can be traversed with a [`Cursor`].
+pub trait CursorApi: Collection {
+	/// Type of cursors.
+	type Cursor<'a>: Cursor<'a, Item = Self::Item>
+	where
+		Self: 'a;
+
+	/// Returns a cursor pointing to the front item of the sequence
+	/// (or to the ghost non-item if the sequence is empty).
+	fn cursor_front(&self) -> Self::Cursor<'_>;
+
+	/// Returns a cursor pointing to the back item of the sequence
+	/// (or to the ghost non-item if the sequence is empty).
+	fn cursor_back(&self) -> Self::Cursor<'_>;
+}
+
+/// Sequence that can be traversed and edited with a [`CursorMut`].
+pub trait CursorMutApi: Collection {
+	/// Type of mutable cursors.
+	type CursorMut<'a>: CursorMut<'a, Item = Self::Item>
+	where
+		Self: 'a;
+
+	/// Returns a mutable cursor pointing to the front item of the sequence
+	/// (or to the ghost non-item if the sequence is empty).
+	fn cursor_front_mut(&mut self) -> Self::CursorMut<'_>;
+
+	/// Returns a mutable cursor pointing to the back item of the sequence
+	/// (or to the ghost non-item if the sequence is empty).
+	fn cursor_back_mut(&mut self) -> Self::CursorMut<'_>;
+}
+
+/// Cursor over any sequence providing random access to its items.
+pub struct IndexCursor<'a, C: ?Sized> {
+	sequence: &'a C,
+	index: Option<usize>,
+}
+
+impl<'a, C: ?Sized + Len> IndexCursor<'a, C> {
+	/// Creates a new cursor pointing to the front item of the given sequence.
+	pub fn front(sequence: &'a C) -> Self {
+		let index = if sequence.is_empty() { None } else { Some(0) };
+		Self { sequence, index }
+	}
+
+	/// Creates a new cursor pointing to the back item of the given sequence.
+	pub fn back(sequence: &'a C) -> Self {
+		let index = sequence.len().checked_sub(1);
+		Self { sequence, index }
+	}
+}
+
+impl<'a, C: ?Sized> Clone for IndexCursor<'a, C> {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<'a, C: ?Sized> Copy for IndexCursor<'a, C> {}
+
+impl<'a, C: ?Sized + Get<usize> + Len> Cursor<'a> for IndexCursor<'a, C> {
+	type Item = C::Item;
+
+	type ItemRef = C::ItemRef<'a>;
+
+	#[inline(always)]
+	fn index(&self) -> Option<usize> {
+		self.index
+	}
+
+	fn move_next(&mut self) {
+		self.index = match self.index {
+			None if self.sequence.is_empty() => None,
+			None => Some(0),
+			Some(i) if i + 1 < self.sequence.len() => Some(i + 1),
+			Some(_) => None,
+		}
+	}
+
+	fn move_prev(&mut self) {
+		self.index = match self.index {
+			None => self.sequence.len().checked_sub(1),
+			Some(i) => i.checked_sub(1),
+		}
+	}
+
+	#[inline(always)]
+	fn current(&self) -> Option<C::ItemRef<'a>> {
+		self.index.and_then(|i| self.sequence.get(i))
+	}
+}
+
+#[cfg(feature = "alloc")]
+mod alloc_cursors {
+	use super::{Cursor, CursorMut};
+	use alloc::collections::{LinkedList, VecDeque};
+
+	/// Cursor over a [`LinkedList`].
+	///
+	/// Since `LinkedList` does not expose its nodes,
+	/// moving this cursor takes `O(min(i, n - i))` time.
+	/// Use a [`LinkedListCursorMut`] for constant time moves.
+	pub struct LinkedListCursor<'a, T> {
+		list: &'a LinkedList<T>,
+		index: Option<usize>,
+		current: Option<&'a T>,
+	}
+
+	impl<'a, T> LinkedListCursor<'a, T> {
+		/// Creates a new cursor pointing to the front item of the given list.
+		pub fn front(list: &'a LinkedList<T>) -> Self {
+			let index = if list.is_empty() { None } else { Some(0) };
+			Self {
+				list,
+				index,
+				current: list.front(),
+			}
+		}
+
+		/// Creates a new cursor pointing to the back item of the given list.
+		pub fn back(list: &'a LinkedList<T>) -> Self {
+			Self {
+				list,
+				index: list.len().checked_sub(1),
+				current: list.back(),
+			}
+		}
+
+		fn seek(&mut self, index: Option<usize>) {
+			let len = self.list.len();
+			self.index = index;
+			self.current = index.and_then(|i| {
+				if i <= len / 2 {
+					self.list.iter().nth(i)
+				} else {
+					self.list.iter().rev().nth(len - 1 - i)
+				}
+			})
+		}
+	}
+
+	impl<'a, T> Clone for LinkedListCursor<'a, T> {
+		fn clone(&self) -> Self {
+			*self
+		}
+	}
+
+	impl<'a, T> Copy for LinkedListCursor<'a, T> {}
+
+	impl<'a, T> Cursor<'a> for LinkedListCursor<'a, T> {
+		type Item = T;
+
+		type ItemRef = &'a T;
+
+		#[inline(always)]
+		fn index(&self) -> Option<usize> {
+			self.index
+		}
+
+		fn move_next(&mut self) {
+			let index = match self.index {
+				None if self.list.is_empty() => None,
+				None => Some(0),
+				Some(i) if i + 1 < self.list.len() => Some(i + 1),
+				Some(_) => None,
+			};
+
+			self.seek(index)
+		}
+
+		fn move_prev(&mut self) {
+			let index = match self.index {
+				None => self.list.len().checked_sub(1),
+				Some(i) => i.checked_sub(1),
+			};
+
+			self.seek(index)
+		}
+
+		#[inline(always)]
+		fn current(&self) -> Option<&'a T> {
+			self.current
+		}
+	}
+
+	/// Mutable cursor over a [`LinkedList`].
+	///
+	/// The list is split at the cursor position while the cursor lives,
+	/// so that every operation takes constant time.
+	pub struct LinkedListCursorMut<'a, T> {
+		/// Items before the cursor.
+		list: &'a mut LinkedList<T>,
+
+		/// Current item (if any) followed by the items after the cursor.
+		tail: LinkedList<T>,
+	}
+
+	impl<'a, T> LinkedListCursorMut<'a, T> {
+		/// Creates a new mutable cursor pointing to the front item of the given list.
+		pub fn front(list: &'a mut LinkedList<T>) -> Self {
+			let tail = core::mem::take(list);
+			Self { list, tail }
+		}
+
+		/// Creates a new mutable cursor pointing to the back item of the given list.
+		pub fn back(list: &'a mut LinkedList<T>) -> Self {
+			let tail = match list.len() {
+				0 => LinkedList::new(),
+				len => list.split_off(len - 1),
+			};
+
+			Self { list, tail }
+		}
+	}
+
+	impl<'a, T> Drop for LinkedListCursorMut<'a, T> {
+		fn drop(&mut self) {
+			self.list.append(&mut self.tail)
+		}
+	}
+
+	impl<'a, T> CursorMut<'a> for LinkedListCursorMut<'a, T> {
+		type Item = T;
+
+		type Split = LinkedList<T>;
+
+		#[inline(always)]
+		fn index(&self) -> Option<usize> {
+			if self.tail.is_empty() {
+				None
+			} else {
+				Some(self.list.len())
+			}
+		}
+
+		fn move_next(&mut self) {
+			match self.tail.pop_front() {
+				Some(item) => self.list.push_back(item),
+				None => core::mem::swap(self.list, &mut self.tail),
+			}
+		}
+
+		fn move_prev(&mut self) {
+			match self.list.pop_back() {
+				Some(item) => self.tail.push_front(item),
+				None => self.list.append(&mut self.tail),
+			}
+		}
+
+		#[inline(always)]
+		fn current(&mut self) -> Option<&mut T> {
+			self.tail.front_mut()
+		}
+
+		fn insert_before(&mut self, item: T) {
+			self.list.push_back(item)
+		}
+
+		fn insert_after(&mut self, item: T) {
+			match self.tail.pop_front() {
+				Some(current) => {
+					self.tail.push_front(item);
+					self.tail.push_front(current)
+				}
+				None => self.list.push_front(item),
+			}
+		}
+
+		#[inline(always)]
+		fn remove_current(&mut self) -> Option<T> {
+			self.tail.pop_front()
+		}
+
+		fn split_after(&mut self) -> LinkedList<T> {
+			if self.tail.is_empty() {
+				core::mem::take(self.list)
+			} else {
+				self.tail.split_off(1)
+			}
+		}
+	}
+
+	/// Mutable cursor over a [`VecDeque`].
+	pub struct VecDequeCursorMut<'a, T> {
+		deque: &'a mut VecDeque<T>,
+		index: Option<usize>,
+	}
+
+	impl<'a, T> VecDequeCursorMut<'a, T> {
+		/// Creates a new mutable cursor pointing to the front item of the given deque.
+		pub fn front(deque: &'a mut VecDeque<T>) -> Self {
+			let index = if deque.is_empty() { None } else { Some(0) };
+			Self { deque, index }
+		}
+
+		/// Creates a new mutable cursor pointing to the back item of the given deque.
+		pub fn back(deque: &'a mut VecDeque<T>) -> Self {
+			let index = deque.len().checked_sub(1);
+			Self { deque, index }
+		}
+	}
+
+	impl<'a, T> CursorMut<'a> for VecDequeCursorMut<'a, T> {
+		type Item = T;
+
+		type Split = VecDeque<T>;
+
+		#[inline(always)]
+		fn index(&self) -> Option<usize> {
+			self.index
+		}
+
+		fn move_next(&mut self) {
+			self.index = match self.index {
+				None if self.deque.is_empty() => None,
+				None => Some(0),
+				Some(i) if i + 1 < self.deque.len() => Some(i + 1),
+				Some(_) => None,
+			}
+		}
+
+		fn move_prev(&mut self) {
+			self.index = match self.index {
+				None => self.deque.len().checked_sub(1),
+				Some(i) => i.checked_sub(1),
+			}
+		}
+
+		#[inline(always)]
+		fn current(&mut self) -> Option<&mut T> {
+			match self.index {
+				Some(i) => self.deque.get_mut(i),
+				None => None,
+			}
+		}
+
+		fn insert_before(&mut self, item: T) {
+			match self.index {
+				Some(i) => {
+					self.deque.insert(i, item);
+					self.index = Some(i + 1)
+				}
+				None => self.deque.push_back(item),
+			}
+		}
+
+		fn insert_after(&mut self, item: T) {
+			match self.index {
+				Some(i) => self.deque.insert(i + 1, item),
+				None => self.deque.push_front(item),
+			}
+		}
+
+		fn remove_current(&mut self) -> Option<T> {
+			let i = self.index?;
+			let item = self.deque.remove(i);
+			if i >= self.deque.len() {
+				self.index = None
+			}
+
+			item
+		}
+
+		fn split_after(&mut self) -> VecDeque<T> {
+			match self.index {
+				Some(i) => self.deque.split_off(i + 1),
+				None => core::mem::take(self.deque),
+			}
+		}
+	}
+}
+
+#[cfg(feature = "alloc")]
+pub use alloc_cursors::*;
@@ -0,0 +1,356 @@
+//! Object-safe collection traits.
+//!
+//! The traits of this crate use generic associated types (such as
+//! [`CollectionRef::ItemRef`] or [`Iter::Iter`]) which prevent them from
+//! being used as trait objects.
+//! This module provides an object-safe layer returning plain references
+//! and boxed iterators instead, implemented for every collection whose
+//! references are simple references
+//! (see [`SimpleCollectionRef`] and [`SimpleKeyedRef`]).
+//!
+//! ```
+//! use cc_traits::dynamic::DynMapMut;
+//! use std::collections::BTreeMap;
+//!
+//! fn new_map(sorted: bool) -> Box<dyn DynMapMut<String, u32>> {
+//!     #[cfg(feature = "std")]
+//!     {
+//!         if !sorted {
+//!             return Box::new(std::collections::HashMap::new());
+//!         }
+//!     }
+//!
+//!     Box::new(BTreeMap::new())
+//! }
+//!
+//! let mut map = new_map(true);
+//! map.insert("a".to_string(), 1);
+//! assert_eq!(map.get(&"a".to_string()), Some(&1));
+//! ```
+//!
+//! The [`BoxedMap`] wrapper can be used to pass such a map where the
+//! static [`MapMut`](crate::MapMut) traits are expected.
+use crate::{
+	Back, BackMut, Collection, CollectionMut, CollectionRef, Get, GetKeyValue, GetMut, Insert,
+	Iter, Keyed, KeyedRef, Len, MapInsert, MapIter, PopBack, PushBack, Remove, RemoveEntry,
+	SimpleCollectionMut, SimpleCollectionRef, SimpleKeyedRef,
+};
+use alloc::boxed::Box;
+
+/// Object-safe version of [`Get`].
+pub trait DynGet<T>: Collection {
+	/// Returns a reference to the item stored behind the given key (if any).
+	fn get(&self, key: T) -> Option<&Self::Item>;
+
+	/// Checks if the collection contains an item behind the given key.
+	fn contains(&self, key: T) -> bool {
+		self.get(key).is_some()
+	}
+}
+
+impl<T, C: SimpleCollectionRef + Get<T>> DynGet<T> for C {
+	#[inline(always)]
+	fn get(&self, key: T) -> Option<&C::Item> {
+		Get::get(self, key).map(C::into_ref)
+	}
+
+	#[inline(always)]
+	fn contains(&self, key: T) -> bool {
+		Get::contains(self, key)
+	}
+}
+
+/// Object-safe version of [`GetMut`].
+pub trait DynGetMut<T>: DynGet<T> {
+	/// Returns a mutable reference to the item stored behind the given key (if any).
+	fn get_mut(&mut self, key: T) -> Option<&mut Self::Item>;
+}
+
+impl<T, C: SimpleCollectionRef + SimpleCollectionMut + GetMut<T>> DynGetMut<T> for C {
+	#[inline(always)]
+	fn get_mut(&mut self, key: T) -> Option<&mut C::Item> {
+		GetMut::get_mut(self, key).map(C::into_mut)
+	}
+}
+
+/// Object-safe version of [`GetKeyValue`].
+pub trait DynGetKeyValue<T>: Keyed {
+	/// Returns the key-value pair stored behind the given key (if any).
+	fn get_key_value(&self, key: T) -> Option<(&Self::Key, &Self::Item)>;
+}
+
+impl<T, C: SimpleCollectionRef + SimpleKeyedRef + GetKeyValue<T>> DynGetKeyValue<T> for C {
+	#[inline(always)]
+	fn get_key_value(&self, key: T) -> Option<(&C::Key, &C::Item)> {
+		GetKeyValue::get_key_value(self, key).map(|(k, v)| {
+			(<C as SimpleKeyedRef>::into_ref(k), <C as SimpleCollectionRef>::into_ref(v))
+		})
+	}
+}
+
+/// Object-safe version of [`Back`].
+pub trait DynBack: Collection {
+	/// Returns a reference to the back item, if any.
+	fn back(&self) -> Option<&Self::Item>;
+}
+
+impl<C: SimpleCollectionRef + Back> DynBack for C {
+	#[inline(always)]
+	fn back(&self) -> Option<&C::Item> {
+		Back::back(self).map(C::into_ref)
+	}
+}
+
+/// Object-safe version of [`BackMut`].
+pub trait DynBackMut: DynBack {
+	/// Returns a mutable reference to the back item, if any.
+	fn back_mut(&mut self) -> Option<&mut Self::Item>;
+}
+
+impl<C: SimpleCollectionRef + SimpleCollectionMut + Back + BackMut> DynBackMut for C {
+	#[inline(always)]
+	fn back_mut(&mut self) -> Option<&mut C::Item> {
+		BackMut::back_mut(self).map(C::into_mut)
+	}
+}
+
+/// Object-safe version of [`Iter`].
+pub trait DynIter: Collection {
+	/// Create an iterator over the items of the collection.
+	fn iter(&self) -> Box<dyn Iterator<Item = &Self::Item> + '_>;
+}
+
+impl<C: SimpleCollectionRef + Iter> DynIter for C {
+	#[inline(always)]
+	fn iter(&self) -> Box<dyn Iterator<Item = &C::Item> + '_> {
+		Box::new(Iter::iter(self).map(C::into_ref))
+	}
+}
+
+/// Object-safe version of [`MapIter`].
+pub trait DynMapIter: Keyed {
+	/// Create an iterator over the key-value pairs of the collection.
+	fn iter(&self) -> Box<dyn Iterator<Item = (&Self::Key, &Self::Item)> + '_>;
+}
+
+impl<C: SimpleCollectionRef + SimpleKeyedRef + MapIter> DynMapIter for C {
+	#[inline(always)]
+	fn iter(&self) -> Box<dyn Iterator<Item = (&C::Key, &C::Item)> + '_> {
+		Box::new(MapIter::iter(self).map(|(k, v)| {
+			(<C as SimpleKeyedRef>::into_ref(k), <C as SimpleCollectionRef>::into_ref(v))
+		}))
+	}
+}
+
+/// Object-safe immutable stack.
+///
+/// See [`Stack`](crate::Stack).
+pub trait DynStack<T>: Collection<Item = T> + Len + DynBack + DynIter {}
+
+impl<T, C: Collection<Item = T> + Len + DynBack + DynIter> DynStack<T> for C {}
+
+/// Object-safe mutable stack.
+///
+/// See [`StackMut`](crate::StackMut). Only stacks whose
+/// [`PushBack`] operation cannot fail implement this trait.
+pub trait DynStackMut<T>: DynStack<T> + DynBackMut + PushBack<Output = ()> + PopBack {}
+
+impl<T, C: DynStack<T> + DynBackMut + PushBack<Output = ()> + PopBack> DynStackMut<T> for C {}
+
+/// Object-safe immutable set.
+///
+/// See [`Set`](crate::Set).
+pub trait DynSet<T>: Collection<Item = T> + Len + for<'a> DynGet<&'a T> + DynIter {}
+
+impl<T, C: Collection<Item = T> + Len + for<'a> DynGet<&'a T> + DynIter> DynSet<T> for C {}
+
+/// Object-safe mutable set.
+///
+/// See [`SetMut`](crate::SetMut).
+pub trait DynSetMut<T>: DynSet<T> + Insert<Output = bool> + for<'a> Remove<&'a T> {}
+
+impl<T, C: DynSet<T> + Insert<Output = bool> + for<'a> Remove<&'a T>> DynSetMut<T> for C {}
+
+/// Object-safe immutable map.
+///
+/// See [`Map`](crate::Map).
+pub trait DynMap<K, V>:
+	Keyed<Key = K, Item = V>
+	+ Len
+	+ for<'a> DynGet<&'a K>
+	+ for<'a> DynGetKeyValue<&'a K>
+	+ DynMapIter
+{
+}
+
+impl<K, V, C> DynMap<K, V> for C where
+	C: Keyed<Key = K, Item = V>
+		+ Len
+		+ for<'a> DynGet<&'a K>
+		+ for<'a> DynGetKeyValue<&'a K>
+		+ DynMapIter
+{
+}
+
+/// Object-safe mutable map.
+///
+/// See [`MapMut`](crate::MapMut).
+pub trait DynMapMut<K, V>:
+	DynMap<K, V>
+	+ for<'a> DynGetMut<&'a K>
+	+ MapInsert<K, Output = Option<V>>
+	+ for<'a> Remove<&'a K>
+	+ for<'a> RemoveEntry<&'a K>
+{
+}
+
+impl<K, V, C> DynMapMut<K, V> for C where
+	C: DynMap<K, V>
+		+ for<'a> DynGetMut<&'a K>
+		+ MapInsert<K, Output = Option<V>>
+		+ for<'a> Remove<&'a K>
+		+ for<'a> RemoveEntry<&'a K>
+{
+}
+
+/// Boxed [`DynMapMut`] trait object implementing the static map traits.
+///
+/// This makes it possible to pass a map chosen at runtime to functions
+/// expecting a [`MapMut`](crate::MapMut).
+pub struct BoxedMap<'m, K, V>(Box<dyn DynMapMut<K, V> + 'm>);
+
+impl<'m, K, V> BoxedMap<'m, K, V> {
+	/// Wraps the given map.
+	pub fn new(map: Box<dyn DynMapMut<K, V> + 'm>) -> Self {
+		Self(map)
+	}
+
+	/// Returns the underlying map.
+	pub fn into_inner(self) -> Box<dyn DynMapMut<K, V> + 'm> {
+		self.0
+	}
+}
+
+impl<'m, K, V> From<Box<dyn DynMapMut<K, V> + 'm>> for BoxedMap<'m, K, V> {
+	#[inline(always)]
+	fn from(map: Box<dyn DynMapMut<K, V> + 'm>) -> Self {
+		Self(map)
+	}
+}
+
+impl<'m, K, V> Collection for BoxedMap<'m, K, V> {
+	type Item = V;
+}
+
+impl<'m, K, V> CollectionRef for BoxedMap<'m, K, V> {
+	type ItemRef<'a> = &'a V where Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<'m, K, V> CollectionMut for BoxedMap<'m, K, V> {
+	type ItemMut<'a> = &'a mut V where Self: 'a;
+
+	crate::covariant_item_mut!();
+}
+
+impl<'m, K, V> SimpleCollectionRef for BoxedMap<'m, K, V> {
+	crate::simple_collection_ref!();
+}
+
+impl<'m, K, V> SimpleCollectionMut for BoxedMap<'m, K, V> {
+	crate::simple_collection_mut!();
+}
+
+impl<'m, K, V> Keyed for BoxedMap<'m, K, V> {
+	type Key = K;
+}
+
+impl<'m, K, V> KeyedRef for BoxedMap<'m, K, V> {
+	type KeyRef<'a> = &'a K where Self: 'a;
+
+	crate::covariant_key_ref!();
+}
+
+impl<'m, K, V> SimpleKeyedRef for BoxedMap<'m, K, V> {
+	crate::simple_keyed_ref!();
+}
+
+impl<'m, K, V> Len for BoxedMap<'m, K, V> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+}
+
+impl<'m, 'k, K, V> Get<&'k K> for BoxedMap<'m, K, V> {
+	#[inline(always)]
+	fn get(&self, key: &'k K) -> Option<&V> {
+		self.0.get(key)
+	}
+
+	#[inline(always)]
+	fn contains(&self, key: &'k K) -> bool {
+		self.0.contains(key)
+	}
+}
+
+impl<'m, 'k, K, V> GetMut<&'k K> for BoxedMap<'m, K, V> {
+	#[inline(always)]
+	fn get_mut(&mut self, key: &'k K) -> Option<&mut V> {
+		self.0.get_mut(key)
+	}
+}
+
+impl<'m, 'k, K, V> GetKeyValue<&'k K> for BoxedMap<'m, K, V> {
+	#[inline(always)]
+	fn get_key_value(&self, key: &'k K) -> Option<(&K, &V)> {
+		self.0.get_key_value(key)
+	}
+}
+
+impl<'m, K, V> MapInsert<K> for BoxedMap<'m, K, V> {
+	type Output = Option<V>;
+
+	#[inline(always)]
+	fn insert(&mut self, key: K, value: V) -> Option<V> {
+		self.0.insert(key, value)
+	}
+}
+
+impl<'m, 'k, K, V> Remove<&'k K> for BoxedMap<'m, K, V> {
+	#[inline(always)]
+	fn remove(&mut self, key: &'k K) -> Option<V> {
+		self.0.remove(key)
+	}
+}
+
+impl<'m, 'k, K, V> RemoveEntry<&'k K> for BoxedMap<'m, K, V> {
+	#[inline(always)]
+	fn remove_entry(&mut self, key: &'k K) -> Option<(K, V)> {
+		self.0.remove_entry(key)
+	}
+}
+
+impl<'m, K, V> Iter for BoxedMap<'m, K, V> {
+	type Iter<'a> = Box<dyn Iterator<Item = &'a V> + 'a> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		Box::new(self.0.iter().map(|(_, v)| v))
+	}
+}
+
+impl<'m, K, V> MapIter for BoxedMap<'m, K, V> {
+	type Iter<'a> = Box<dyn Iterator<Item = (&'a K, &'a V)> + 'a> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.0.iter()
+	}
+}
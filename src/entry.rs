@@ -0,0 +1,164 @@
+use crate::Keyed;
+
+/// View into an occupied entry of a map.
+pub trait OccupiedEntry<'a> {
+	/// Type of the entry's key.
+	type Key;
+
+	/// Type of the entry's value.
+	type Item;
+
+	/// Returns a reference to the entry's key.
+	fn key(&self) -> &Self::Key;
+
+	/// Returns a reference to the entry's value.
+	fn get(&self) -> &Self::Item;
+
+	/// Returns a mutable reference to the entry's value.
+	fn get_mut(&mut self) -> &mut Self::Item;
+
+	/// Converts the entry into a mutable reference to its value,
+	/// living as long as the map.
+	fn into_mut(self) -> &'a mut Self::Item;
+
+	/// Replaces the entry's value, and returns the old value.
+	fn insert(&mut self, value: Self::Item) -> Self::Item;
+
+	/// Removes the entry from the map and returns its key and value.
+	fn remove_entry(self) -> (Self::Key, Self::Item);
+
+	/// Removes the entry from the map and returns its value.
+	fn remove(self) -> Self::Item
+	where
+		Self: Sized,
+	{
+		self.remove_entry().1
+	}
+}
+
+/// View into a vacant entry of a map.
+pub trait VacantEntry<'a> {
+	/// Type of the entry's key.
+	type Key;
+
+	/// Type of the entry's value.
+	type Item;
+
+	/// Returns a reference to the key that would be used when inserting a value
+	/// through this entry.
+	fn key(&self) -> &Self::Key;
+
+	/// Inserts a value in the map with the entry's key,
+	/// and returns a mutable reference to it.
+	fn insert(self, value: Self::Item) -> &'a mut Self::Item;
+}
+
+/// View into a single entry of a map, either occupied or vacant.
+///
+/// This is returned by the [`EntryApi::entry`] function.
+pub enum Entry<O, V> {
+	/// Occupied entry.
+	Occupied(O),
+
+	/// Vacant entry.
+	Vacant(V),
+}
+
+impl<'a, O, V> Entry<O, V>
+where
+	O: OccupiedEntry<'a>,
+	V: VacantEntry<'a, Key = O::Key, Item = O::Item>,
+{
+	/// Returns a reference to the entry's key.
+	pub fn key(&self) -> &O::Key {
+		match self {
+			Self::Occupied(o) => o.key(),
+			Self::Vacant(v) => v.key(),
+		}
+	}
+
+	/// Ensures a value is in the entry by inserting `default` if it is vacant,
+	/// and returns a mutable reference to the value.
+	pub fn or_insert(self, default: O::Item) -> &'a mut O::Item {
+		match self {
+			Self::Occupied(o) => o.into_mut(),
+			Self::Vacant(v) => v.insert(default),
+		}
+	}
+
+	/// Ensures a value is in the entry by inserting the result of `default` if it is vacant,
+	/// and returns a mutable reference to the value.
+	pub fn or_insert_with<F>(self, default: F) -> &'a mut O::Item
+	where
+		F: FnOnce() -> O::Item,
+	{
+		match self {
+			Self::Occupied(o) => o.into_mut(),
+			Self::Vacant(v) => v.insert(default()),
+		}
+	}
+
+	/// Ensures a value is in the entry by inserting the result of `default`
+	/// applied to the entry's key if it is vacant,
+	/// and returns a mutable reference to the value.
+	pub fn or_insert_with_key<F>(self, default: F) -> &'a mut O::Item
+	where
+		F: FnOnce(&O::Key) -> O::Item,
+	{
+		match self {
+			Self::Occupied(o) => o.into_mut(),
+			Self::Vacant(v) => {
+				let value = default(v.key());
+				v.insert(value)
+			}
+		}
+	}
+
+	/// Ensures a value is in the entry by inserting the default value if it is vacant,
+	/// and returns a mutable reference to the value.
+	pub fn or_default(self) -> &'a mut O::Item
+	where
+		O::Item: Default,
+	{
+		self.or_insert_with(Default::default)
+	}
+
+	/// Calls `f` on the entry's value if it is occupied.
+	pub fn and_modify<F>(mut self, f: F) -> Self
+	where
+		F: FnOnce(&mut O::Item),
+	{
+		if let Self::Occupied(o) = &mut self {
+			f(o.get_mut())
+		}
+
+		self
+	}
+
+	/// Removes the entry from the map if it is occupied,
+	/// and returns its key and value.
+	pub fn remove_entry(self) -> Option<(O::Key, O::Item)> {
+		match self {
+			Self::Occupied(o) => Some(o.remove_entry()),
+			Self::Vacant(_) => None,
+		}
+	}
+}
+
+/// Map providing in-place manipulation of its entries.
+///
+/// This allows one to query and update the map with a single lookup.
+pub trait EntryApi<K>: Keyed {
+	/// Type of occupied entries.
+	type Occupied<'a>: OccupiedEntry<'a, Key = Self::Key, Item = Self::Item>
+	where
+		Self: 'a;
+
+	/// Type of vacant entries.
+	type Vacant<'a>: VacantEntry<'a, Key = Self::Key, Item = Self::Item>
+	where
+		Self: 'a;
+
+	/// Gets the entry for the given `key` in the map.
+	fn entry(&mut self, key: K) -> Entry<Self::Occupied<'_>, Self::Vacant<'_>>;
+}
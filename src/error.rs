@@ -0,0 +1,92 @@
+use core::fmt;
+
+/// Error returned when a fixed-capacity collection is full.
+///
+/// The element that could not be added to the collection, if any,
+/// is given back with the error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CapacityError<T = ()> {
+	element: T,
+}
+
+impl<T> CapacityError<T> {
+	/// Creates a new capacity error carrying the rejected `element`.
+	pub const fn new(element: T) -> Self {
+		Self { element }
+	}
+
+	/// Returns a reference to the element that could not be added.
+	pub fn element(&self) -> &T {
+		&self.element
+	}
+
+	/// Returns the element that could not be added.
+	pub fn into_element(self) -> T {
+		self.element
+	}
+
+	/// Drops the rejected element, keeping only the error.
+	pub fn simplify(self) -> CapacityError {
+		CapacityError::new(())
+	}
+}
+
+impl<T> fmt::Display for CapacityError<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "capacity exceeded")
+	}
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug> std::error::Error for CapacityError<T> {}
+
+/// Error returned when an element cannot be pushed because the collection
+/// failed to allocate memory for it.
+///
+/// The element that could not be added to the collection is given back
+/// with the allocation error `E`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TryPushError<T, E> {
+	element: T,
+	error: E,
+}
+
+impl<T, E> TryPushError<T, E> {
+	/// Creates a new error carrying the rejected `element` and the allocation `error`.
+	pub const fn new(element: T, error: E) -> Self {
+		Self { element, error }
+	}
+
+	/// Returns a reference to the element that could not be added.
+	pub fn element(&self) -> &T {
+		&self.element
+	}
+
+	/// Returns the element that could not be added.
+	pub fn into_element(self) -> T {
+		self.element
+	}
+
+	/// Returns a reference to the allocation error.
+	pub fn error(&self) -> &E {
+		&self.error
+	}
+
+	/// Drops the rejected element, keeping only the allocation error.
+	pub fn into_error(self) -> E {
+		self.error
+	}
+}
+
+impl<T, E> fmt::Display for TryPushError<T, E> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "memory allocation failed")
+	}
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug, E: std::error::Error + 'static> std::error::Error for TryPushError<T, E> {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		Some(&self.error)
+	}
+}
@@ -0,0 +1,163 @@
+use crate::{
+	Append, Capacity, Clear, Collection, CollectionMut, CollectionRef, Drain, ExtendItems,
+	IntoItems, Iter, Len, Peek, PeekMut, PopMax, PushPriority, Reserve, SimpleCollectionRef,
+	TryReserve, TryWithCapacity, WithCapacity,
+};
+use alloc::collections::{binary_heap, BinaryHeap, TryReserveError};
+
+impl<T> Collection for BinaryHeap<T> {
+	type Item = T;
+}
+
+impl<T> CollectionRef for BinaryHeap<T> {
+	type ItemRef<'a> = &'a T where Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+/// The only item that can be mutably referenced is the greatest one, through [`PeekMut`].
+/// The heap is reordered when the reference is dropped.
+impl<T: Ord> CollectionMut for BinaryHeap<T> {
+	type ItemMut<'a> = binary_heap::PeekMut<'a, T> where Self: 'a;
+
+	crate::covariant_item_mut!();
+}
+
+impl<T> SimpleCollectionRef for BinaryHeap<T> {
+	crate::simple_collection_ref!();
+}
+
+impl<T: Ord> WithCapacity for BinaryHeap<T> {
+	#[inline(always)]
+	fn with_capacity(capacity: usize) -> Self {
+		BinaryHeap::with_capacity(capacity)
+	}
+}
+
+impl<T> Len for BinaryHeap<T> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.is_empty()
+	}
+}
+
+impl<T> Capacity for BinaryHeap<T> {
+	#[inline(always)]
+	fn capacity(&self) -> usize {
+		self.capacity()
+	}
+}
+
+impl<T> Reserve for BinaryHeap<T> {
+	#[inline(always)]
+	fn reserve(&mut self, additional: usize) {
+		self.reserve(additional)
+	}
+}
+
+impl<T> Peek for BinaryHeap<T> {
+	#[inline(always)]
+	fn peek(&self) -> Option<&T> {
+		self.peek()
+	}
+}
+
+impl<T: Ord> PeekMut for BinaryHeap<T> {
+	#[inline(always)]
+	fn peek_mut(&mut self) -> Option<binary_heap::PeekMut<'_, T>> {
+		self.peek_mut()
+	}
+}
+
+impl<T: Ord> PushPriority for BinaryHeap<T> {
+	type Output = ();
+
+	#[inline(always)]
+	fn push(&mut self, t: T) {
+		self.push(t)
+	}
+}
+
+impl<T: Ord> PopMax for BinaryHeap<T> {
+	#[inline(always)]
+	fn pop_max(&mut self) -> Option<T> {
+		self.pop()
+	}
+}
+
+impl<T> Clear for BinaryHeap<T> {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.clear()
+	}
+}
+
+/// Iterates over the items in arbitrary order.
+impl<T> Iter for BinaryHeap<T> {
+	type Iter<'a> = binary_heap::Iter<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.iter()
+	}
+}
+
+impl<T> Drain for BinaryHeap<T> {
+	type Drain<'a> = binary_heap::Drain<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn drain(&mut self) -> Self::Drain<'_> {
+		self.drain()
+	}
+}
+
+impl<T> IntoItems for BinaryHeap<T> {
+	type IntoItems = binary_heap::IntoIter<T>;
+
+	#[inline(always)]
+	fn into_items(self) -> Self::IntoItems {
+		self.into_iter()
+	}
+}
+
+impl<T: Ord> ExtendItems for BinaryHeap<T> {
+	#[inline(always)]
+	fn extend_items<I>(&mut self, items: I)
+	where
+		I: IntoIterator<Item = T>,
+	{
+		self.extend(items)
+	}
+}
+
+impl<T> TryReserve for BinaryHeap<T> {
+	type Error = TryReserveError;
+
+	#[inline(always)]
+	fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+		self.try_reserve(additional)
+	}
+}
+
+impl<T: Ord> TryWithCapacity for BinaryHeap<T> {
+	type Error = TryReserveError;
+
+	#[inline(always)]
+	fn try_with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+		let mut result = Self::new();
+		result.try_reserve(capacity)?;
+		Ok(result)
+	}
+}
+
+impl<T: Ord> Append for BinaryHeap<T> {
+	#[inline(always)]
+	fn append(&mut self, other: &mut Self) {
+		self.append(other)
+	}
+}
@@ -0,0 +1,55 @@
+use crate::{IntoItems, IntoKeys, IntoValues, MapIntoItems, SplitOff, WithCapacity};
+use alloc::boxed::Box;
+
+forward_ref!([C: ?Sized] Box<C>);
+forward_mut!([C: ?Sized] Box<C>);
+
+impl<C: IntoItems> IntoItems for Box<C> {
+	type IntoItems = C::IntoItems;
+
+	#[inline(always)]
+	fn into_items(self) -> Self::IntoItems {
+		C::into_items(*self)
+	}
+}
+
+impl<C: MapIntoItems> MapIntoItems for Box<C> {
+	type IntoItems = C::IntoItems;
+
+	#[inline(always)]
+	fn into_items(self) -> Self::IntoItems {
+		C::into_items(*self)
+	}
+}
+
+impl<C: IntoKeys> IntoKeys for Box<C> {
+	type IntoKeys = C::IntoKeys;
+
+	#[inline(always)]
+	fn into_keys(self) -> Self::IntoKeys {
+		C::into_keys(*self)
+	}
+}
+
+impl<C: IntoValues> IntoValues for Box<C> {
+	type IntoValues = C::IntoValues;
+
+	#[inline(always)]
+	fn into_values(self) -> Self::IntoValues {
+		C::into_values(*self)
+	}
+}
+
+impl<C: SplitOff> SplitOff for Box<C> {
+	#[inline(always)]
+	fn split_off(&mut self, at: usize) -> Self {
+		Box::new(C::split_off(self, at))
+	}
+}
+
+impl<C: WithCapacity> WithCapacity for Box<C> {
+	#[inline(always)]
+	fn with_capacity(capacity: usize) -> Self {
+		Box::new(C::with_capacity(capacity))
+	}
+}
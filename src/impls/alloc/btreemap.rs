@@ -1,10 +1,17 @@
 use crate::{
-	Clear, Collection, CollectionMut, CollectionRef, Get, GetKeyValue, GetMut, Iter, Keyed,
-	KeyedRef, Len, MapInsert, MapIter, MapIterMut, Remove, SimpleCollectionMut,
-	SimpleCollectionRef, SimpleKeyedRef,
+	Append, Ceiling, CeilingKeyValue, Clear, Collection, CollectionMut, CollectionRef, Drain, Entry,
+	EntryApi, ExtendMap, First, FirstKeyValue, Floor, FloorKeyValue, Get, GetKeyValue,
+	GetKeyValueMut, GetMut, IntoItems, IntoKeys, IntoValues, Iter, Keyed, KeyedRef, Keys, Last,
+	LastKeyValue, Len, MapDrain, MapInsert, MapIntoItems, MapIter, MapIterMut, MapRange, MapRetain,
+	OccupiedEntry, Ordered, PopFirst, PopFirstKeyValue, PopLast, PopLastKeyValue, Range, Remove,
+	RemoveEntry, Retain, SimpleCollectionMut, SimpleCollectionRef, SimpleKeyedRef, VacantEntry,
+	Values, ValuesMut, WithCapacity,
 };
 use alloc::collections::BTreeMap;
-use core::borrow::Borrow;
+use core::{
+	borrow::Borrow,
+	ops::{Bound, RangeBounds},
+};
 
 impl<K, V> Collection for BTreeMap<K, V> {
 	type Item = V;
@@ -44,6 +51,14 @@ impl<K, V> SimpleKeyedRef for BTreeMap<K, V> {
 	crate::simple_keyed_ref!();
 }
 
+impl<K, V> WithCapacity for BTreeMap<K, V> {
+	/// The capacity is ignored, since nodes are allocated on insertion.
+	#[inline(always)]
+	fn with_capacity(_capacity: usize) -> Self {
+		BTreeMap::new()
+	}
+}
+
 impl<K, V> Len for BTreeMap<K, V> {
 	#[inline(always)]
 	fn len(&self) -> usize {
@@ -89,6 +104,17 @@ where
 	}
 }
 
+impl<'a, Q, K: Ord, V> GetKeyValueMut<&'a Q> for BTreeMap<K, V>
+where
+	K: Borrow<Q>,
+	Q: Ord + ?Sized,
+{
+	#[inline(always)]
+	fn get_key_value_mut(&mut self, key: &'a Q) -> Option<(&K, &mut V)> {
+		self.range_mut::<Q, _>((Bound::Included(key), Bound::Included(key))).next()
+	}
+}
+
 impl<K: Ord, V> MapInsert<K> for BTreeMap<K, V> {
 	type Output = Option<V>;
 
@@ -109,6 +135,17 @@ where
 	}
 }
 
+impl<'a, Q, K: Ord, V> RemoveEntry<&'a Q> for BTreeMap<K, V>
+where
+	K: Borrow<Q>,
+	Q: Ord + ?Sized,
+{
+	#[inline(always)]
+	fn remove_entry(&mut self, key: &'a Q) -> Option<(K, V)> {
+		self.remove_entry(key)
+	}
+}
+
 impl<K: Ord, V> Clear for BTreeMap<K, V> {
 	#[inline(always)]
 	fn clear(&mut self) {
@@ -142,3 +179,324 @@ impl<K, V> MapIterMut for BTreeMap<K, V> {
 		self.iter_mut()
 	}
 }
+
+impl<K: Ord, V> EntryApi<K> for BTreeMap<K, V> {
+	type Occupied<'a> = alloc::collections::btree_map::OccupiedEntry<'a, K, V> where Self: 'a;
+	type Vacant<'a> = alloc::collections::btree_map::VacantEntry<'a, K, V> where Self: 'a;
+
+	#[inline(always)]
+	fn entry(&mut self, key: K) -> Entry<Self::Occupied<'_>, Self::Vacant<'_>> {
+		match self.entry(key) {
+			alloc::collections::btree_map::Entry::Occupied(o) => Entry::Occupied(o),
+			alloc::collections::btree_map::Entry::Vacant(v) => Entry::Vacant(v),
+		}
+	}
+}
+
+impl<'a, K: Ord, V> OccupiedEntry<'a> for alloc::collections::btree_map::OccupiedEntry<'a, K, V> {
+	type Key = K;
+	type Item = V;
+
+	#[inline(always)]
+	fn key(&self) -> &K {
+		self.key()
+	}
+
+	#[inline(always)]
+	fn get(&self) -> &V {
+		self.get()
+	}
+
+	#[inline(always)]
+	fn get_mut(&mut self) -> &mut V {
+		self.get_mut()
+	}
+
+	#[inline(always)]
+	fn into_mut(self) -> &'a mut V {
+		self.into_mut()
+	}
+
+	#[inline(always)]
+	fn insert(&mut self, value: V) -> V {
+		self.insert(value)
+	}
+
+	#[inline(always)]
+	fn remove_entry(self) -> (K, V) {
+		self.remove_entry()
+	}
+
+	#[inline(always)]
+	fn remove(self) -> V {
+		self.remove()
+	}
+}
+
+impl<'a, K: Ord, V> VacantEntry<'a> for alloc::collections::btree_map::VacantEntry<'a, K, V> {
+	type Key = K;
+	type Item = V;
+
+	#[inline(always)]
+	fn key(&self) -> &K {
+		self.key()
+	}
+
+	#[inline(always)]
+	fn insert(self, value: V) -> &'a mut V {
+		self.insert(value)
+	}
+}
+
+impl<K: Ord, V> Ordered for BTreeMap<K, V> {}
+
+impl<Q, R, K, V> Range<Q, R> for BTreeMap<K, V>
+where
+	K: Ord + Borrow<Q>,
+	Q: Ord + ?Sized,
+	R: RangeBounds<Q>,
+{
+	type Range<'a> = core::iter::Map<
+		alloc::collections::btree_map::Range<'a, K, V>,
+		fn((&'a K, &'a V)) -> &'a V,
+	> where Self: 'a;
+
+	#[inline(always)]
+	fn range(&self, range: R) -> Self::Range<'_> {
+		self.range::<Q, R>(range).map(|(_, v)| v)
+	}
+}
+
+impl<Q, R, K, V> MapRange<Q, R> for BTreeMap<K, V>
+where
+	K: Ord + Borrow<Q>,
+	Q: Ord + ?Sized,
+	R: RangeBounds<Q>,
+{
+	type Range<'a> = alloc::collections::btree_map::Range<'a, K, V> where Self: 'a;
+
+	#[inline(always)]
+	fn range(&self, range: R) -> Self::Range<'_> {
+		self.range::<Q, R>(range)
+	}
+}
+
+impl<K: Ord, V> First for BTreeMap<K, V> {
+	#[inline(always)]
+	fn first(&self) -> Option<&V> {
+		self.first_key_value().map(|(_, v)| v)
+	}
+}
+
+impl<K: Ord, V> Last for BTreeMap<K, V> {
+	#[inline(always)]
+	fn last(&self) -> Option<&V> {
+		self.last_key_value().map(|(_, v)| v)
+	}
+}
+
+impl<K: Ord, V> FirstKeyValue for BTreeMap<K, V> {
+	#[inline(always)]
+	fn first_key_value(&self) -> Option<(&K, &V)> {
+		self.first_key_value()
+	}
+}
+
+impl<K: Ord, V> LastKeyValue for BTreeMap<K, V> {
+	#[inline(always)]
+	fn last_key_value(&self) -> Option<(&K, &V)> {
+		self.last_key_value()
+	}
+}
+
+impl<K: Ord, V> PopFirst for BTreeMap<K, V> {
+	#[inline(always)]
+	fn pop_first(&mut self) -> Option<V> {
+		self.pop_first().map(|(_, v)| v)
+	}
+}
+
+impl<K: Ord, V> PopLast for BTreeMap<K, V> {
+	#[inline(always)]
+	fn pop_last(&mut self) -> Option<V> {
+		self.pop_last().map(|(_, v)| v)
+	}
+}
+
+impl<K: Ord, V> PopFirstKeyValue for BTreeMap<K, V> {
+	#[inline(always)]
+	fn pop_first_key_value(&mut self) -> Option<(K, V)> {
+		self.pop_first()
+	}
+}
+
+impl<K: Ord, V> PopLastKeyValue for BTreeMap<K, V> {
+	#[inline(always)]
+	fn pop_last_key_value(&mut self) -> Option<(K, V)> {
+		self.pop_last()
+	}
+}
+
+impl<'a, Q, K: Ord, V> Floor<&'a Q> for BTreeMap<K, V>
+where
+	K: Borrow<Q>,
+	Q: Ord + ?Sized,
+{
+	#[inline(always)]
+	fn floor(&self, key: &'a Q) -> Option<&V> {
+		self.floor_key_value(key).map(|(_, v)| v)
+	}
+}
+
+impl<'a, Q, K: Ord, V> Ceiling<&'a Q> for BTreeMap<K, V>
+where
+	K: Borrow<Q>,
+	Q: Ord + ?Sized,
+{
+	#[inline(always)]
+	fn ceiling(&self, key: &'a Q) -> Option<&V> {
+		self.ceiling_key_value(key).map(|(_, v)| v)
+	}
+}
+
+impl<'a, Q, K: Ord, V> FloorKeyValue<&'a Q> for BTreeMap<K, V>
+where
+	K: Borrow<Q>,
+	Q: Ord + ?Sized,
+{
+	#[inline(always)]
+	fn floor_key_value(&self, key: &'a Q) -> Option<(&K, &V)> {
+		self.range::<Q, _>((Bound::Unbounded, Bound::Included(key)))
+			.next_back()
+	}
+}
+
+impl<'a, Q, K: Ord, V> CeilingKeyValue<&'a Q> for BTreeMap<K, V>
+where
+	K: Borrow<Q>,
+	Q: Ord + ?Sized,
+{
+	#[inline(always)]
+	fn ceiling_key_value(&self, key: &'a Q) -> Option<(&K, &V)> {
+		self.range::<Q, _>((Bound::Included(key), Bound::Unbounded))
+			.next()
+	}
+}
+
+impl<K, V> Drain for BTreeMap<K, V> {
+	type Drain<'a> = alloc::collections::btree_map::IntoValues<K, V> where Self: 'a;
+
+	#[inline(always)]
+	fn drain(&mut self) -> Self::Drain<'_> {
+		core::mem::take(self).into_values()
+	}
+}
+
+impl<K, V> MapDrain for BTreeMap<K, V> {
+	type Drain<'a> = alloc::collections::btree_map::IntoIter<K, V> where Self: 'a;
+
+	#[inline(always)]
+	fn drain(&mut self) -> Self::Drain<'_> {
+		core::mem::take(self).into_iter()
+	}
+}
+
+impl<K: Ord, V> Retain for BTreeMap<K, V> {
+	#[inline(always)]
+	fn retain<F>(&mut self, mut f: F)
+	where
+		F: FnMut(&V) -> bool,
+	{
+		self.retain(|_, v| f(v))
+	}
+}
+
+impl<K: Ord, V> MapRetain for BTreeMap<K, V> {
+	#[inline(always)]
+	fn retain<F>(&mut self, f: F)
+	where
+		F: FnMut(&K, &mut V) -> bool,
+	{
+		self.retain(f)
+	}
+}
+
+impl<K, V> IntoItems for BTreeMap<K, V> {
+	type IntoItems = alloc::collections::btree_map::IntoValues<K, V>;
+
+	#[inline(always)]
+	fn into_items(self) -> Self::IntoItems {
+		self.into_values()
+	}
+}
+
+impl<K, V> MapIntoItems for BTreeMap<K, V> {
+	type IntoItems = alloc::collections::btree_map::IntoIter<K, V>;
+
+	#[inline(always)]
+	fn into_items(self) -> Self::IntoItems {
+		self.into_iter()
+	}
+}
+
+impl<K: Ord, V> ExtendMap<K> for BTreeMap<K, V> {
+	#[inline(always)]
+	fn extend_map<I>(&mut self, items: I)
+	where
+		I: IntoIterator<Item = (K, V)>,
+	{
+		self.extend(items)
+	}
+}
+
+impl<K: Ord, V> Append for BTreeMap<K, V> {
+	#[inline(always)]
+	fn append(&mut self, other: &mut Self) {
+		self.append(other)
+	}
+}
+
+impl<K, V> Keys for BTreeMap<K, V> {
+	type Keys<'a> = alloc::collections::btree_map::Keys<'a, K, V> where Self: 'a;
+
+	#[inline(always)]
+	fn keys(&self) -> Self::Keys<'_> {
+		self.keys()
+	}
+}
+
+impl<K, V> Values for BTreeMap<K, V> {
+	type Values<'a> = alloc::collections::btree_map::Values<'a, K, V> where Self: 'a;
+
+	#[inline(always)]
+	fn values(&self) -> Self::Values<'_> {
+		self.values()
+	}
+}
+
+impl<K, V> ValuesMut for BTreeMap<K, V> {
+	type ValuesMut<'a> = alloc::collections::btree_map::ValuesMut<'a, K, V> where Self: 'a;
+
+	#[inline(always)]
+	fn values_mut(&mut self) -> Self::ValuesMut<'_> {
+		self.values_mut()
+	}
+}
+
+impl<K, V> IntoKeys for BTreeMap<K, V> {
+	type IntoKeys = alloc::collections::btree_map::IntoKeys<K, V>;
+
+	#[inline(always)]
+	fn into_keys(self) -> Self::IntoKeys {
+		self.into_keys()
+	}
+}
+
+impl<K, V> IntoValues for BTreeMap<K, V> {
+	type IntoValues = alloc::collections::btree_map::IntoValues<K, V>;
+
+	#[inline(always)]
+	fn into_values(self) -> Self::IntoValues {
+		self.into_values()
+	}
+}
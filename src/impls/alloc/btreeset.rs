@@ -1,9 +1,15 @@
 use crate::{
-	Clear, Collection, CollectionMut, CollectionRef, Get, Insert, Iter, Len, Remove,
-	SimpleCollectionMut, SimpleCollectionRef,
+	Append, Ceiling, Clear, Collection, CollectionMut, CollectionRef, Difference, Drain,
+	ExtendItems, First, Floor, Get, Insert, Intersection, IntoItems, IsDisjoint, IsSubset, Iter,
+	Last, Len, Ordered, Peek, PopFirst, PopLast, PopMax, PushPriority, Range, Remove, RemoveIn,
+	Retain, RetainIn, SimpleCollectionMut, SimpleCollectionRef, SymmetricDifference,
+	SymmetricDifferenceWith, Union, UnionWith, WithCapacity,
 };
 use alloc::collections::BTreeSet;
-use core::borrow::Borrow;
+use core::{
+	borrow::Borrow,
+	ops::{Bound, RangeBounds},
+};
 
 impl<T> Collection for BTreeSet<T> {
 	type Item = T;
@@ -29,6 +35,14 @@ impl<T> SimpleCollectionMut for BTreeSet<T> {
 	crate::simple_collection_mut!();
 }
 
+impl<T> WithCapacity for BTreeSet<T> {
+	/// The capacity is ignored, since nodes are allocated on insertion.
+	#[inline(always)]
+	fn with_capacity(_capacity: usize) -> Self {
+		BTreeSet::new()
+	}
+}
+
 impl<T> Len for BTreeSet<T> {
 	#[inline(always)]
 	fn len(&self) -> usize {
@@ -87,3 +101,230 @@ impl<T> Iter for BTreeSet<T> {
 		self.iter()
 	}
 }
+
+impl<T: Ord> Union for BTreeSet<T> {
+	type Union<'a> = alloc::collections::btree_set::Union<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn union<'a>(&'a self, other: &'a Self) -> Self::Union<'a> {
+		self.union(other)
+	}
+}
+
+impl<T: Ord> UnionWith for BTreeSet<T> {
+	#[inline(always)]
+	fn union_with(&mut self, mut other: Self) {
+		self.append(&mut other)
+	}
+}
+
+impl<T: Ord> Intersection for BTreeSet<T> {
+	type Intersection<'a> = alloc::collections::btree_set::Intersection<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn intersection<'a>(&'a self, other: &'a Self) -> Self::Intersection<'a> {
+		self.intersection(other)
+	}
+}
+
+impl<T: Ord> RetainIn for BTreeSet<T> {
+	#[inline(always)]
+	fn retain_in(&mut self, other: &Self) {
+		self.retain(|t| other.contains(t))
+	}
+}
+
+impl<T: Ord> Difference for BTreeSet<T> {
+	type Difference<'a> = alloc::collections::btree_set::Difference<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn difference<'a>(&'a self, other: &'a Self) -> Self::Difference<'a> {
+		self.difference(other)
+	}
+}
+
+impl<T: Ord> RemoveIn for BTreeSet<T> {
+	fn remove_in(&mut self, other: &Self) {
+		if other.len() < self.len() {
+			for t in other {
+				self.remove(t);
+			}
+		} else {
+			self.retain(|t| !other.contains(t))
+		}
+	}
+}
+
+impl<T: Ord> SymmetricDifference for BTreeSet<T> {
+	type SymmetricDifference<'a> = alloc::collections::btree_set::SymmetricDifference<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn symmetric_difference<'a>(&'a self, other: &'a Self) -> Self::SymmetricDifference<'a> {
+		self.symmetric_difference(other)
+	}
+}
+
+impl<T: Ord> SymmetricDifferenceWith for BTreeSet<T> {
+	fn symmetric_difference_with(&mut self, other: Self) {
+		for t in other {
+			if !self.remove(&t) {
+				self.insert(t);
+			}
+		}
+	}
+}
+
+impl<T: Ord> IsSubset for BTreeSet<T> {
+	#[inline(always)]
+	fn is_subset(&self, other: &Self) -> bool {
+		self.is_subset(other)
+	}
+
+	#[inline(always)]
+	fn is_superset(&self, other: &Self) -> bool {
+		self.is_superset(other)
+	}
+}
+
+impl<T: Ord> IsDisjoint for BTreeSet<T> {
+	#[inline(always)]
+	fn is_disjoint(&self, other: &Self) -> bool {
+		self.is_disjoint(other)
+	}
+}
+
+impl<T: Ord> Ordered for BTreeSet<T> {}
+
+impl<Q, R, T> Range<Q, R> for BTreeSet<T>
+where
+	T: Ord + Borrow<Q>,
+	Q: Ord + ?Sized,
+	R: RangeBounds<Q>,
+{
+	type Range<'a> = alloc::collections::btree_set::Range<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn range(&self, range: R) -> Self::Range<'_> {
+		self.range::<Q, R>(range)
+	}
+}
+
+impl<T: Ord> First for BTreeSet<T> {
+	#[inline(always)]
+	fn first(&self) -> Option<&T> {
+		self.first()
+	}
+}
+
+impl<T: Ord> Last for BTreeSet<T> {
+	#[inline(always)]
+	fn last(&self) -> Option<&T> {
+		self.last()
+	}
+}
+
+impl<T: Ord> PopFirst for BTreeSet<T> {
+	#[inline(always)]
+	fn pop_first(&mut self) -> Option<T> {
+		self.pop_first()
+	}
+}
+
+impl<T: Ord> PopLast for BTreeSet<T> {
+	#[inline(always)]
+	fn pop_last(&mut self) -> Option<T> {
+		self.pop_last()
+	}
+}
+
+impl<T: Ord> Peek for BTreeSet<T> {
+	#[inline(always)]
+	fn peek(&self) -> Option<&T> {
+		self.last()
+	}
+}
+
+impl<T: Ord> PushPriority for BTreeSet<T> {
+	type Output = bool;
+
+	#[inline(always)]
+	fn push(&mut self, t: T) -> bool {
+		self.insert(t)
+	}
+}
+
+impl<T: Ord> PopMax for BTreeSet<T> {
+	#[inline(always)]
+	fn pop_max(&mut self) -> Option<T> {
+		self.pop_last()
+	}
+}
+
+impl<'a, Q, T: Ord> Floor<&'a Q> for BTreeSet<T>
+where
+	T: Borrow<Q>,
+	Q: Ord + ?Sized,
+{
+	#[inline(always)]
+	fn floor(&self, t: &'a Q) -> Option<&T> {
+		self.range::<Q, _>((Bound::Unbounded, Bound::Included(t)))
+			.next_back()
+	}
+}
+
+impl<'a, Q, T: Ord> Ceiling<&'a Q> for BTreeSet<T>
+where
+	T: Borrow<Q>,
+	Q: Ord + ?Sized,
+{
+	#[inline(always)]
+	fn ceiling(&self, t: &'a Q) -> Option<&T> {
+		self.range::<Q, _>((Bound::Included(t), Bound::Unbounded))
+			.next()
+	}
+}
+
+impl<T> Drain for BTreeSet<T> {
+	type Drain<'a> = alloc::collections::btree_set::IntoIter<T> where Self: 'a;
+
+	#[inline(always)]
+	fn drain(&mut self) -> Self::Drain<'_> {
+		core::mem::take(self).into_iter()
+	}
+}
+
+impl<T: Ord> Retain for BTreeSet<T> {
+	#[inline(always)]
+	fn retain<F>(&mut self, f: F)
+	where
+		F: FnMut(&T) -> bool,
+	{
+		self.retain(f)
+	}
+}
+
+impl<T> IntoItems for BTreeSet<T> {
+	type IntoItems = alloc::collections::btree_set::IntoIter<T>;
+
+	#[inline(always)]
+	fn into_items(self) -> Self::IntoItems {
+		self.into_iter()
+	}
+}
+
+impl<T: Ord> ExtendItems for BTreeSet<T> {
+	#[inline(always)]
+	fn extend_items<I>(&mut self, items: I)
+	where
+		I: IntoIterator<Item = T>,
+	{
+		self.extend(items)
+	}
+}
+
+impl<T: Ord> Append for BTreeSet<T> {
+	#[inline(always)]
+	fn append(&mut self, other: &mut Self) {
+		self.append(other)
+	}
+}
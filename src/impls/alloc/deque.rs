@@ -1,8 +1,13 @@
 use crate::{
-	Back, BackMut, Capacity, Clear, Collection, CollectionMut, CollectionRef, Front, FrontMut, Len,
-	PopBack, PushBack, Reserve, SimpleCollectionMut, SimpleCollectionRef, WithCapacity,
+	Append, AsSlices, AsSlicesMut, BinarySearch, Capacity, Clear, Collection, CollectionMut,
+	CollectionRef, CursorApi, CursorMutApi, Dedup, Drain, DrainRange, ExtendItems, Get, GetMut,
+	IndexCursor, InsertAt, IntoItems, Iter, IterMut, Len, PopBack, PopFront, PushBack, PushFront,
+	Remove, Reserve, Resize, ResizeWith, Retain, Reverse, Rotate, SimpleCollectionMut,
+	SimpleCollectionRef, Sort, SortUnstable, SplitOff, SwapRemove, Truncate, TryPushBack,
+	TryPushError, TryReserve, TryWithCapacity, VecDequeCursorMut, WithCapacity,
 };
-use alloc::collections::VecDeque;
+use alloc::collections::{TryReserveError, VecDeque};
+use core::{cmp::Ordering, ops::RangeBounds};
 
 impl<T> Collection for VecDeque<T> {
 	type Item = T;
@@ -61,31 +66,26 @@ impl<T> Reserve for VecDeque<T> {
 	}
 }
 
-impl<T> Front for VecDeque<T> {
+impl<T> Get<usize> for VecDeque<T> {
 	#[inline(always)]
-	fn front(&self) -> Option<&T> {
-		self.front()
+	fn get(&self, index: usize) -> Option<&T> {
+		self.get(index)
 	}
 }
 
-impl<T> FrontMut for VecDeque<T> {
+impl<T> GetMut<usize> for VecDeque<T> {
 	#[inline(always)]
-	fn front_mut(&mut self) -> Option<&mut T> {
-		self.front_mut()
+	fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+		self.get_mut(index)
 	}
 }
 
-impl<T> Back for VecDeque<T> {
-	#[inline(always)]
-	fn back(&self) -> Option<&T> {
-		self.back()
-	}
-}
+impl<T> PushFront for VecDeque<T> {
+	type Output = ();
 
-impl<T> BackMut for VecDeque<T> {
 	#[inline(always)]
-	fn back_mut(&mut self) -> Option<&mut T> {
-		self.back_mut()
+	fn push_front(&mut self, t: T) {
+		self.push_front(t)
 	}
 }
 
@@ -105,9 +105,345 @@ impl<T> PopBack for VecDeque<T> {
 	}
 }
 
+impl<T> PopFront for VecDeque<T> {
+	#[inline(always)]
+	fn pop_front(&mut self) -> Option<T> {
+		self.pop_front()
+	}
+}
+
+impl<T> Remove<usize> for VecDeque<T> {
+	#[inline(always)]
+	fn remove(&mut self, index: usize) -> Option<T> {
+		self.remove(index)
+	}
+}
+
 impl<T> Clear for VecDeque<T> {
 	#[inline(always)]
 	fn clear(&mut self) {
 		self.clear()
 	}
 }
+
+impl<T> Iter for VecDeque<T> {
+	type Iter<'a> = alloc::collections::vec_deque::Iter<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.iter()
+	}
+}
+
+impl<T> IterMut for VecDeque<T> {
+	type IterMut<'a> = alloc::collections::vec_deque::IterMut<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn iter_mut(&mut self) -> Self::IterMut<'_> {
+		self.iter_mut()
+	}
+}
+
+impl<T> Drain for VecDeque<T> {
+	type Drain<'a> = alloc::collections::vec_deque::Drain<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn drain(&mut self) -> Self::Drain<'_> {
+		self.drain(..)
+	}
+}
+
+impl<T, R: RangeBounds<usize>> DrainRange<R> for VecDeque<T> {
+	type DrainRange<'a> = alloc::collections::vec_deque::Drain<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn drain_range(&mut self, range: R) -> Self::DrainRange<'_> {
+		self.drain(range)
+	}
+}
+
+impl<T> Retain for VecDeque<T> {
+	#[inline(always)]
+	fn retain<F>(&mut self, f: F)
+	where
+		F: FnMut(&T) -> bool,
+	{
+		self.retain(f)
+	}
+}
+
+impl<T> IntoItems for VecDeque<T> {
+	type IntoItems = alloc::collections::vec_deque::IntoIter<T>;
+
+	#[inline(always)]
+	fn into_items(self) -> Self::IntoItems {
+		self.into_iter()
+	}
+}
+
+impl<T> ExtendItems for VecDeque<T> {
+	#[inline(always)]
+	fn extend_items<I>(&mut self, items: I)
+	where
+		I: IntoIterator<Item = T>,
+	{
+		self.extend(items)
+	}
+}
+
+impl<T> TryReserve for VecDeque<T> {
+	type Error = TryReserveError;
+
+	#[inline(always)]
+	fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+		self.try_reserve(additional)
+	}
+}
+
+impl<T> TryWithCapacity for VecDeque<T> {
+	type Error = TryReserveError;
+
+	#[inline(always)]
+	fn try_with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+		let mut result = Self::new();
+		result.try_reserve(capacity)?;
+		Ok(result)
+	}
+}
+
+impl<T> TryPushBack for VecDeque<T> {
+	type Error = TryPushError<T, TryReserveError>;
+
+	#[inline(always)]
+	fn try_push_back(&mut self, element: T) -> Result<(), Self::Error> {
+		match self.try_reserve(1) {
+			Ok(()) => {
+				self.push_back(element);
+				Ok(())
+			}
+			Err(e) => Err(TryPushError::new(element, e)),
+		}
+	}
+}
+
+impl<T> CursorApi for VecDeque<T> {
+	type Cursor<'a> = IndexCursor<'a, Self> where Self: 'a;
+
+	#[inline(always)]
+	fn cursor_front(&self) -> Self::Cursor<'_> {
+		IndexCursor::front(self)
+	}
+
+	#[inline(always)]
+	fn cursor_back(&self) -> Self::Cursor<'_> {
+		IndexCursor::back(self)
+	}
+}
+
+impl<T> CursorMutApi for VecDeque<T> {
+	type CursorMut<'a> = VecDequeCursorMut<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn cursor_front_mut(&mut self) -> Self::CursorMut<'_> {
+		VecDequeCursorMut::front(self)
+	}
+
+	#[inline(always)]
+	fn cursor_back_mut(&mut self) -> Self::CursorMut<'_> {
+		VecDequeCursorMut::back(self)
+	}
+}
+
+impl<T> InsertAt for VecDeque<T> {
+	#[inline(always)]
+	fn insert_at(&mut self, index: usize, element: T) {
+		self.insert(index, element)
+	}
+}
+
+impl<T> SwapRemove for VecDeque<T> {
+	#[inline(always)]
+	fn swap_remove(&mut self, index: usize) -> Option<T> {
+		self.swap_remove_back(index)
+	}
+}
+
+impl<T> Truncate for VecDeque<T> {
+	#[inline(always)]
+	fn truncate(&mut self, len: usize) {
+		self.truncate(len)
+	}
+}
+
+impl<T: Clone> Resize for VecDeque<T> {
+	#[inline(always)]
+	fn resize(&mut self, new_len: usize, value: T) {
+		self.resize(new_len, value)
+	}
+}
+
+impl<T> ResizeWith for VecDeque<T> {
+	#[inline(always)]
+	fn resize_with<F>(&mut self, new_len: usize, f: F)
+	where
+		F: FnMut() -> T,
+	{
+		self.resize_with(new_len, f)
+	}
+}
+
+impl<T> SplitOff for VecDeque<T> {
+	#[inline(always)]
+	fn split_off(&mut self, at: usize) -> Self {
+		self.split_off(at)
+	}
+}
+
+impl<T> Append for VecDeque<T> {
+	#[inline(always)]
+	fn append(&mut self, other: &mut Self) {
+		self.append(other)
+	}
+}
+
+impl<T> AsSlices for VecDeque<T> {
+	#[inline(always)]
+	fn as_slices(&self) -> (&[T], &[T]) {
+		self.as_slices()
+	}
+}
+
+impl<T> AsSlicesMut for VecDeque<T> {
+	#[inline(always)]
+	fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+		self.as_mut_slices()
+	}
+}
+
+impl<T> Sort for VecDeque<T> {
+	#[inline(always)]
+	fn sort(&mut self)
+	where
+		T: Ord,
+	{
+		self.make_contiguous().sort()
+	}
+
+	#[inline(always)]
+	fn sort_by<F>(&mut self, compare: F)
+	where
+		F: FnMut(&T, &T) -> Ordering,
+	{
+		self.make_contiguous().sort_by(compare)
+	}
+
+	#[inline(always)]
+	fn sort_by_key<K, F>(&mut self, f: F)
+	where
+		K: Ord,
+		F: FnMut(&T) -> K,
+	{
+		self.make_contiguous().sort_by_key(f)
+	}
+}
+
+impl<T> SortUnstable for VecDeque<T> {
+	#[inline(always)]
+	fn sort_unstable(&mut self)
+	where
+		T: Ord,
+	{
+		self.make_contiguous().sort_unstable()
+	}
+
+	#[inline(always)]
+	fn sort_unstable_by<F>(&mut self, compare: F)
+	where
+		F: FnMut(&T, &T) -> Ordering,
+	{
+		self.make_contiguous().sort_unstable_by(compare)
+	}
+
+	#[inline(always)]
+	fn sort_unstable_by_key<K, F>(&mut self, f: F)
+	where
+		K: Ord,
+		F: FnMut(&T) -> K,
+	{
+		self.make_contiguous().sort_unstable_by_key(f)
+	}
+}
+
+impl<T> BinarySearch for VecDeque<T> {
+	#[inline(always)]
+	fn binary_search(&self, x: &T) -> Result<usize, usize>
+	where
+		T: Ord,
+	{
+		self.binary_search(x)
+	}
+
+	#[inline(always)]
+	fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+	where
+		F: FnMut(&T) -> Ordering,
+	{
+		self.binary_search_by(f)
+	}
+
+	#[inline(always)]
+	fn binary_search_by_key<K, F>(&self, key: &K, f: F) -> Result<usize, usize>
+	where
+		K: Ord,
+		F: FnMut(&T) -> K,
+	{
+		self.binary_search_by_key(key, f)
+	}
+}
+
+impl<T> Reverse for VecDeque<T> {
+	#[inline(always)]
+	fn reverse(&mut self) {
+		self.make_contiguous().reverse()
+	}
+}
+
+impl<T> Rotate for VecDeque<T> {
+	#[inline(always)]
+	fn rotate_left(&mut self, mid: usize) {
+		self.rotate_left(mid)
+	}
+
+	#[inline(always)]
+	fn rotate_right(&mut self, k: usize) {
+		self.rotate_right(k)
+	}
+}
+
+impl<T> Dedup for VecDeque<T> {
+	#[inline(always)]
+	fn dedup(&mut self)
+	where
+		T: PartialEq,
+	{
+		Dedup::dedup_by(self, |a, b| a == b)
+	}
+
+	#[inline(always)]
+	fn dedup_by<F>(&mut self, same_bucket: F)
+	where
+		F: FnMut(&mut T, &mut T) -> bool,
+	{
+		let len = crate::dedup_slice_by(self.make_contiguous(), same_bucket);
+		self.truncate(len)
+	}
+
+	#[inline(always)]
+	fn dedup_by_key<K, F>(&mut self, mut key: F)
+	where
+		K: PartialEq,
+		F: FnMut(&mut T) -> K,
+	{
+		Dedup::dedup_by(self, |a, b| key(a) == key(b))
+	}
+}
@@ -0,0 +1,224 @@
+use crate::{
+	Append, Back, BackMut, Clear, Collection, CollectionMut, CollectionRef, CursorApi, CursorMut,
+	CursorMutApi, Drain, ExtendItems, Front, FrontMut, IntoItems, Iter, IterMut, Len,
+	LinkedListCursor, LinkedListCursorMut, PopBack, PopFront, PushBack, PushFront, Retain,
+	SimpleCollectionMut, SimpleCollectionRef, SplitOff, WithCapacity,
+};
+use alloc::collections::{linked_list, LinkedList};
+
+impl<T> Collection for LinkedList<T> {
+	type Item = T;
+}
+
+impl<T> CollectionRef for LinkedList<T> {
+	type ItemRef<'a> = &'a T where Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<T> CollectionMut for LinkedList<T> {
+	type ItemMut<'a> = &'a mut T where Self: 'a;
+
+	crate::covariant_item_mut!();
+}
+
+impl<T> SimpleCollectionRef for LinkedList<T> {
+	crate::simple_collection_ref!();
+}
+
+impl<T> SimpleCollectionMut for LinkedList<T> {
+	crate::simple_collection_mut!();
+}
+
+impl<T> WithCapacity for LinkedList<T> {
+	/// The capacity is ignored, since nodes are allocated on insertion.
+	#[inline(always)]
+	fn with_capacity(_capacity: usize) -> Self {
+		LinkedList::new()
+	}
+}
+
+impl<T> Len for LinkedList<T> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.is_empty()
+	}
+}
+
+impl<T> Front for LinkedList<T> {
+	#[inline(always)]
+	fn front(&self) -> Option<&T> {
+		self.front()
+	}
+}
+
+impl<T> Back for LinkedList<T> {
+	#[inline(always)]
+	fn back(&self) -> Option<&T> {
+		self.back()
+	}
+}
+
+impl<T> FrontMut for LinkedList<T> {
+	#[inline(always)]
+	fn front_mut(&mut self) -> Option<&mut T> {
+		self.front_mut()
+	}
+}
+
+impl<T> BackMut for LinkedList<T> {
+	#[inline(always)]
+	fn back_mut(&mut self) -> Option<&mut T> {
+		self.back_mut()
+	}
+}
+
+impl<T> PushFront for LinkedList<T> {
+	type Output = ();
+
+	#[inline(always)]
+	fn push_front(&mut self, t: T) {
+		self.push_front(t)
+	}
+}
+
+impl<T> PushBack for LinkedList<T> {
+	type Output = ();
+
+	#[inline(always)]
+	fn push_back(&mut self, t: T) {
+		self.push_back(t)
+	}
+}
+
+impl<T> PopFront for LinkedList<T> {
+	#[inline(always)]
+	fn pop_front(&mut self) -> Option<T> {
+		self.pop_front()
+	}
+}
+
+impl<T> PopBack for LinkedList<T> {
+	#[inline(always)]
+	fn pop_back(&mut self) -> Option<T> {
+		self.pop_back()
+	}
+}
+
+impl<T> Clear for LinkedList<T> {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.clear()
+	}
+}
+
+impl<T> Iter for LinkedList<T> {
+	type Iter<'a> = linked_list::Iter<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.iter()
+	}
+}
+
+impl<T> IterMut for LinkedList<T> {
+	type IterMut<'a> = linked_list::IterMut<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn iter_mut(&mut self) -> Self::IterMut<'_> {
+		self.iter_mut()
+	}
+}
+
+/// The list is emptied immediately, before the iterator is consumed.
+impl<T> Drain for LinkedList<T> {
+	type Drain<'a> = linked_list::IntoIter<T> where Self: 'a;
+
+	#[inline(always)]
+	fn drain(&mut self) -> Self::Drain<'_> {
+		core::mem::take(self).into_iter()
+	}
+}
+
+impl<T> Retain for LinkedList<T> {
+	#[inline(always)]
+	fn retain<F>(&mut self, mut f: F)
+	where
+		F: FnMut(&T) -> bool,
+	{
+		let mut cursor = LinkedListCursorMut::front(self);
+		while let Some(t) = cursor.current() {
+			if f(t) {
+				cursor.move_next()
+			} else {
+				cursor.remove_current();
+			}
+		}
+	}
+}
+
+impl<T> IntoItems for LinkedList<T> {
+	type IntoItems = linked_list::IntoIter<T>;
+
+	#[inline(always)]
+	fn into_items(self) -> Self::IntoItems {
+		self.into_iter()
+	}
+}
+
+impl<T> ExtendItems for LinkedList<T> {
+	#[inline(always)]
+	fn extend_items<I>(&mut self, items: I)
+	where
+		I: IntoIterator<Item = T>,
+	{
+		self.extend(items)
+	}
+}
+
+impl<T> CursorApi for LinkedList<T> {
+	type Cursor<'a> = LinkedListCursor<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn cursor_front(&self) -> Self::Cursor<'_> {
+		LinkedListCursor::front(self)
+	}
+
+	#[inline(always)]
+	fn cursor_back(&self) -> Self::Cursor<'_> {
+		LinkedListCursor::back(self)
+	}
+}
+
+impl<T> CursorMutApi for LinkedList<T> {
+	type CursorMut<'a> = LinkedListCursorMut<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn cursor_front_mut(&mut self) -> Self::CursorMut<'_> {
+		LinkedListCursorMut::front(self)
+	}
+
+	#[inline(always)]
+	fn cursor_back_mut(&mut self) -> Self::CursorMut<'_> {
+		LinkedListCursorMut::back(self)
+	}
+}
+
+impl<T> SplitOff for LinkedList<T> {
+	#[inline(always)]
+	fn split_off(&mut self, at: usize) -> Self {
+		self.split_off(at)
+	}
+}
+
+impl<T> Append for LinkedList<T> {
+	#[inline(always)]
+	fn append(&mut self, other: &mut Self) {
+		self.append(other)
+	}
+}
@@ -1,4 +1,9 @@
+mod binaryheap;
+mod boxed;
 mod btreemap;
 mod btreeset;
 mod deque;
+mod linkedlist;
+mod rc;
+mod string;
 mod vec;
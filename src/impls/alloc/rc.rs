@@ -0,0 +1,8 @@
+use alloc::rc::Rc;
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+
+forward_ref!([C: ?Sized] Rc<C>);
+
+#[cfg(target_has_atomic = "ptr")]
+forward_ref!([C: ?Sized] Arc<C>);
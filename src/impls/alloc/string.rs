@@ -0,0 +1,86 @@
+use crate::{
+	Capacity, Clear, Collection, ExtendItems, Insert, PopBack, PushBack, Reserve, TryReserve,
+	WithCapacity,
+};
+use alloc::{collections::TryReserveError, string::String};
+
+/// A `String` is a growable collection of `char`.
+///
+/// Just like [`str`], characters cannot be referenced individually.
+/// The [`Capacity`] and [`Reserve`] implementations count bytes, not characters.
+impl Collection for String {
+	type Item = char;
+}
+
+impl WithCapacity for String {
+	#[inline(always)]
+	fn with_capacity(capacity: usize) -> Self {
+		String::with_capacity(capacity)
+	}
+}
+
+impl Capacity for String {
+	#[inline(always)]
+	fn capacity(&self) -> usize {
+		self.capacity()
+	}
+}
+
+impl Reserve for String {
+	#[inline(always)]
+	fn reserve(&mut self, additional: usize) {
+		self.reserve(additional)
+	}
+}
+
+impl PushBack for String {
+	type Output = ();
+
+	#[inline(always)]
+	fn push_back(&mut self, c: char) {
+		self.push(c)
+	}
+}
+
+/// Appends the character at the end of the string.
+impl Insert for String {
+	type Output = ();
+
+	#[inline(always)]
+	fn insert(&mut self, c: char) {
+		self.push(c)
+	}
+}
+
+impl PopBack for String {
+	#[inline(always)]
+	fn pop_back(&mut self) -> Option<char> {
+		self.pop()
+	}
+}
+
+impl Clear for String {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.clear()
+	}
+}
+
+impl ExtendItems for String {
+	#[inline(always)]
+	fn extend_items<I>(&mut self, items: I)
+	where
+		I: IntoIterator<Item = char>,
+	{
+		self.extend(items)
+	}
+}
+
+impl TryReserve for String {
+	type Error = TryReserveError;
+
+	#[inline(always)]
+	fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+		self.try_reserve(additional)
+	}
+}
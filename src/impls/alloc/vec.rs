@@ -1,8 +1,12 @@
 use crate::{
-	Capacity, Clear, Collection, CollectionMut, CollectionRef, Get, GetMut, Iter, IterMut, Len,
-	PopBack, PushBack, Remove, Reserve, SimpleCollectionMut, SimpleCollectionRef, WithCapacity,
+	Append, Capacity, Clear, Collection, CollectionMut, CollectionRef, Contiguous, ContiguousMut,
+	Drain, DrainRange, ExtendItems, Get, GetMut, InsertAt, IntoItems, Iter, IterMut, Len, PopBack,
+	PushBack, Remove, Reserve, Resize, ResizeWith, Retain, SimpleCollectionMut,
+	SimpleCollectionRef, SplitOff, SwapRemove, Truncate, TryPushBack, TryPushError, TryReserve,
+	TryWithCapacity, WithCapacity,
 };
-use alloc::vec::Vec;
+use alloc::{collections::TryReserveError, vec::Vec};
+use core::ops::RangeBounds;
 
 impl<T> Collection for Vec<T> {
 	type Item = T;
@@ -126,3 +130,155 @@ impl<T> IterMut for Vec<T> {
 		self.as_mut_slice().iter_mut()
 	}
 }
+
+impl<T> Drain for Vec<T> {
+	type Drain<'a> = alloc::vec::Drain<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn drain(&mut self) -> Self::Drain<'_> {
+		self.drain(..)
+	}
+}
+
+impl<T, R: RangeBounds<usize>> DrainRange<R> for Vec<T> {
+	type DrainRange<'a> = alloc::vec::Drain<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn drain_range(&mut self, range: R) -> Self::DrainRange<'_> {
+		self.drain(range)
+	}
+}
+
+impl<T> Retain for Vec<T> {
+	#[inline(always)]
+	fn retain<F>(&mut self, f: F)
+	where
+		F: FnMut(&T) -> bool,
+	{
+		self.retain(f)
+	}
+}
+
+impl<T> IntoItems for Vec<T> {
+	type IntoItems = alloc::vec::IntoIter<T>;
+
+	#[inline(always)]
+	fn into_items(self) -> Self::IntoItems {
+		self.into_iter()
+	}
+}
+
+impl<T> ExtendItems for Vec<T> {
+	#[inline(always)]
+	fn extend_items<I>(&mut self, items: I)
+	where
+		I: IntoIterator<Item = T>,
+	{
+		self.extend(items)
+	}
+}
+
+impl<T> TryReserve for Vec<T> {
+	type Error = TryReserveError;
+
+	#[inline(always)]
+	fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+		self.try_reserve(additional)
+	}
+}
+
+impl<T> TryWithCapacity for Vec<T> {
+	type Error = TryReserveError;
+
+	#[inline(always)]
+	fn try_with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+		let mut result = Self::new();
+		result.try_reserve(capacity)?;
+		Ok(result)
+	}
+}
+
+impl<T> TryPushBack for Vec<T> {
+	type Error = TryPushError<T, TryReserveError>;
+
+	#[inline(always)]
+	fn try_push_back(&mut self, element: T) -> Result<(), Self::Error> {
+		match self.try_reserve(1) {
+			Ok(()) => {
+				self.push(element);
+				Ok(())
+			}
+			Err(e) => Err(TryPushError::new(element, e)),
+		}
+	}
+}
+
+impl<T> InsertAt for Vec<T> {
+	#[inline(always)]
+	fn insert_at(&mut self, index: usize, element: T) {
+		self.insert(index, element)
+	}
+}
+
+impl<T> SwapRemove for Vec<T> {
+	#[inline(always)]
+	fn swap_remove(&mut self, index: usize) -> Option<T> {
+		if index < self.len() {
+			Some(self.swap_remove(index))
+		} else {
+			None
+		}
+	}
+}
+
+impl<T> Truncate for Vec<T> {
+	#[inline(always)]
+	fn truncate(&mut self, len: usize) {
+		self.truncate(len)
+	}
+}
+
+impl<T: Clone> Resize for Vec<T> {
+	#[inline(always)]
+	fn resize(&mut self, new_len: usize, value: T) {
+		self.resize(new_len, value)
+	}
+}
+
+impl<T> ResizeWith for Vec<T> {
+	#[inline(always)]
+	fn resize_with<F>(&mut self, new_len: usize, f: F)
+	where
+		F: FnMut() -> T,
+	{
+		self.resize_with(new_len, f)
+	}
+}
+
+impl<T> SplitOff for Vec<T> {
+	#[inline(always)]
+	fn split_off(&mut self, at: usize) -> Self {
+		self.split_off(at)
+	}
+}
+
+impl<T> Append for Vec<T> {
+	#[inline(always)]
+	fn append(&mut self, other: &mut Self) {
+		self.append(other)
+	}
+}
+
+impl<T> Contiguous for Vec<T> {
+	#[inline(always)]
+	fn as_slice(&self) -> &[T] {
+		self.as_slice()
+	}
+}
+
+impl<T> ContiguousMut for Vec<T> {
+	#[inline(always)]
+	fn as_mut_slice(&mut self) -> &mut [T] {
+		self.as_mut_slice()
+	}
+}
@@ -0,0 +1,199 @@
+use crate::{
+	Capacity, CapacityError, Clear, Collection, CollectionMut, CollectionRef, Contiguous,
+	ContiguousMut, Drain, DrainRange, Get, GetMut, IntoItems, Iter, IterMut, Len, PopBack, PushBack,
+	Remove, Retain, SimpleCollectionMut, SimpleCollectionRef, TryPushBack, TryReserve,
+	TryWithCapacity,
+};
+use arrayvec::ArrayVec;
+use core::ops::RangeBounds;
+
+impl<T, const CAP: usize> Collection for ArrayVec<T, CAP> {
+	type Item = T;
+}
+
+impl<T, const CAP: usize> CollectionRef for ArrayVec<T, CAP> {
+	type ItemRef<'a> = &'a T where Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<T, const CAP: usize> CollectionMut for ArrayVec<T, CAP> {
+	type ItemMut<'a> = &'a mut T where Self: 'a;
+
+	crate::covariant_item_mut!();
+}
+
+impl<T, const CAP: usize> SimpleCollectionRef for ArrayVec<T, CAP> {
+	crate::simple_collection_ref!();
+}
+
+impl<T, const CAP: usize> SimpleCollectionMut for ArrayVec<T, CAP> {
+	crate::simple_collection_mut!();
+}
+
+impl<T, const CAP: usize> Len for ArrayVec<T, CAP> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.is_empty()
+	}
+}
+
+impl<T, const CAP: usize> Capacity for ArrayVec<T, CAP> {
+	#[inline(always)]
+	fn capacity(&self) -> usize {
+		CAP
+	}
+}
+
+impl<T, const CAP: usize> Get<usize> for ArrayVec<T, CAP> {
+	#[inline(always)]
+	fn get(&self, index: usize) -> Option<&T> {
+		self.as_slice().get(index)
+	}
+}
+
+impl<T, const CAP: usize> GetMut<usize> for ArrayVec<T, CAP> {
+	#[inline(always)]
+	fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+		self.as_mut_slice().get_mut(index)
+	}
+}
+
+impl<T, const CAP: usize> PushBack for ArrayVec<T, CAP> {
+	type Output = Result<(), CapacityError<T>>;
+
+	#[inline(always)]
+	fn push_back(&mut self, t: T) -> Self::Output {
+		self.try_push(t).map_err(|e| CapacityError::new(e.element()))
+	}
+}
+
+impl<T, const CAP: usize> PopBack for ArrayVec<T, CAP> {
+	#[inline(always)]
+	fn pop_back(&mut self) -> Option<T> {
+		self.pop()
+	}
+}
+
+impl<T, const CAP: usize> Remove<usize> for ArrayVec<T, CAP> {
+	#[inline(always)]
+	fn remove(&mut self, index: usize) -> Option<T> {
+		self.pop_at(index)
+	}
+}
+
+impl<T, const CAP: usize> Clear for ArrayVec<T, CAP> {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.clear()
+	}
+}
+
+impl<T, const CAP: usize> Iter for ArrayVec<T, CAP> {
+	type Iter<'a> = core::slice::Iter<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.as_slice().iter()
+	}
+}
+
+impl<T, const CAP: usize> IterMut for ArrayVec<T, CAP> {
+	type IterMut<'a> = core::slice::IterMut<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn iter_mut(&mut self) -> Self::IterMut<'_> {
+		self.as_mut_slice().iter_mut()
+	}
+}
+
+impl<T, const CAP: usize> Drain for ArrayVec<T, CAP> {
+	type Drain<'a> = arrayvec::Drain<'a, T, CAP> where Self: 'a;
+
+	#[inline(always)]
+	fn drain(&mut self) -> Self::Drain<'_> {
+		self.drain(..)
+	}
+}
+
+impl<T, R: RangeBounds<usize>, const CAP: usize> DrainRange<R> for ArrayVec<T, CAP> {
+	type DrainRange<'a> = arrayvec::Drain<'a, T, CAP> where Self: 'a;
+
+	#[inline(always)]
+	fn drain_range(&mut self, range: R) -> Self::DrainRange<'_> {
+		self.drain(range)
+	}
+}
+
+impl<T, const CAP: usize> Retain for ArrayVec<T, CAP> {
+	#[inline(always)]
+	fn retain<F>(&mut self, mut f: F)
+	where
+		F: FnMut(&T) -> bool,
+	{
+		self.retain(|t| f(t))
+	}
+}
+
+impl<T, const CAP: usize> IntoItems for ArrayVec<T, CAP> {
+	type IntoItems = arrayvec::IntoIter<T, CAP>;
+
+	#[inline(always)]
+	fn into_items(self) -> Self::IntoItems {
+		self.into_iter()
+	}
+}
+
+impl<T, const CAP: usize> TryReserve for ArrayVec<T, CAP> {
+	type Error = CapacityError;
+
+	#[inline(always)]
+	fn try_reserve(&mut self, additional: usize) -> Result<(), CapacityError> {
+		if additional <= self.remaining_capacity() {
+			Ok(())
+		} else {
+			Err(CapacityError::new(()))
+		}
+	}
+}
+
+impl<T, const CAP: usize> TryWithCapacity for ArrayVec<T, CAP> {
+	type Error = CapacityError;
+
+	#[inline(always)]
+	fn try_with_capacity(capacity: usize) -> Result<Self, CapacityError> {
+		if capacity <= CAP {
+			Ok(Self::new())
+		} else {
+			Err(CapacityError::new(()))
+		}
+	}
+}
+
+impl<T, const CAP: usize> TryPushBack for ArrayVec<T, CAP> {
+	type Error = CapacityError<T>;
+
+	#[inline(always)]
+	fn try_push_back(&mut self, element: T) -> Result<(), CapacityError<T>> {
+		PushBack::push_back(self, element)
+	}
+}
+
+impl<T, const CAP: usize> Contiguous for ArrayVec<T, CAP> {
+	#[inline(always)]
+	fn as_slice(&self) -> &[T] {
+		self.as_slice()
+	}
+}
+
+impl<T, const CAP: usize> ContiguousMut for ArrayVec<T, CAP> {
+	#[inline(always)]
+	fn as_mut_slice(&mut self) -> &mut [T] {
+		self.as_mut_slice()
+	}
+}
@@ -0,0 +1,95 @@
+use crate::{
+	Collection, CollectionMut, CollectionRef, Contiguous, ContiguousMut, Get, GetMut, IntoItems,
+	Iter, IterMut, Len, SimpleCollectionMut, SimpleCollectionRef,
+};
+
+impl<T, const N: usize> Collection for [T; N] {
+	type Item = T;
+}
+
+impl<T, const N: usize> CollectionRef for [T; N] {
+	type ItemRef<'a> = &'a T where Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<T, const N: usize> CollectionMut for [T; N] {
+	type ItemMut<'a> = &'a mut T where Self: 'a;
+
+	crate::covariant_item_mut!();
+}
+
+impl<T, const N: usize> SimpleCollectionRef for [T; N] {
+	crate::simple_collection_ref!();
+}
+
+impl<T, const N: usize> SimpleCollectionMut for [T; N] {
+	crate::simple_collection_mut!();
+}
+
+impl<T, const N: usize> Len for [T; N] {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		N
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		N == 0
+	}
+}
+
+impl<T, const N: usize> Get<usize> for [T; N] {
+	#[inline(always)]
+	fn get(&self, index: usize) -> Option<&T> {
+		self.as_slice().get(index)
+	}
+}
+
+impl<T, const N: usize> GetMut<usize> for [T; N] {
+	#[inline(always)]
+	fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+		self.as_mut_slice().get_mut(index)
+	}
+}
+
+impl<T, const N: usize> Iter for [T; N] {
+	type Iter<'a> = core::slice::Iter<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.as_slice().iter()
+	}
+}
+
+impl<T, const N: usize> IterMut for [T; N] {
+	type IterMut<'a> = core::slice::IterMut<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn iter_mut(&mut self) -> Self::IterMut<'_> {
+		self.as_mut_slice().iter_mut()
+	}
+}
+
+impl<T, const N: usize> IntoItems for [T; N] {
+	type IntoItems = core::array::IntoIter<T, N>;
+
+	#[inline(always)]
+	fn into_items(self) -> Self::IntoItems {
+		IntoIterator::into_iter(self)
+	}
+}
+
+impl<T, const N: usize> Contiguous for [T; N] {
+	#[inline(always)]
+	fn as_slice(&self) -> &[T] {
+		self
+	}
+}
+
+impl<T, const N: usize> ContiguousMut for [T; N] {
+	#[inline(always)]
+	fn as_mut_slice(&mut self) -> &mut [T] {
+		self
+	}
+}
@@ -0,0 +1,3 @@
+mod array;
+mod slice;
+mod str;
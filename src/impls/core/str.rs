@@ -0,0 +1,9 @@
+use crate::Collection;
+
+/// A string slice is a collection of `char`.
+///
+/// Since characters are encoded in UTF-8, they cannot be referenced
+/// individually and `str` does not implement [`CollectionRef`](crate::CollectionRef).
+impl Collection for str {
+	type Item = char;
+}
@@ -0,0 +1,76 @@
+use crate::{
+	concurrent::{ConcurrentPop, ConcurrentPush},
+	Capacity, CapacityError, Collection, Len,
+};
+use crossbeam_queue::{ArrayQueue, SegQueue};
+
+impl<T> Collection for SegQueue<T> {
+	type Item = T;
+}
+
+impl<T> Len for SegQueue<T> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.is_empty()
+	}
+}
+
+impl<T> ConcurrentPush for SegQueue<T> {
+	type Output = ();
+
+	#[inline(always)]
+	fn push(&self, element: T) {
+		self.push(element)
+	}
+}
+
+impl<T> ConcurrentPop for SegQueue<T> {
+	#[inline(always)]
+	fn pop(&self) -> Option<T> {
+		self.pop()
+	}
+}
+
+impl<T> Collection for ArrayQueue<T> {
+	type Item = T;
+}
+
+impl<T> Len for ArrayQueue<T> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.is_empty()
+	}
+}
+
+impl<T> Capacity for ArrayQueue<T> {
+	#[inline(always)]
+	fn capacity(&self) -> usize {
+		self.capacity()
+	}
+}
+
+impl<T> ConcurrentPush for ArrayQueue<T> {
+	type Output = Result<(), CapacityError<T>>;
+
+	#[inline(always)]
+	fn push(&self, element: T) -> Self::Output {
+		self.push(element).map_err(CapacityError::new)
+	}
+}
+
+impl<T> ConcurrentPop for ArrayQueue<T> {
+	#[inline(always)]
+	fn pop(&self) -> Option<T> {
+		self.pop()
+	}
+}
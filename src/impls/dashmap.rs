@@ -0,0 +1,125 @@
+use crate::{
+	concurrent::{ConcurrentGet, ConcurrentInsert, ConcurrentMapInsert, ConcurrentRemove},
+	Collection, Keyed, Len,
+};
+use core::{
+	borrow::Borrow,
+	hash::{BuildHasher, Hash},
+};
+use dashmap::{mapref, setref, DashMap, DashSet};
+
+impl<K, V, S> Collection for DashMap<K, V, S> {
+	type Item = V;
+}
+
+impl<K, V, S> Keyed for DashMap<K, V, S> {
+	type Key = K;
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher + Clone> Len for DashMap<K, V, S> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.is_empty()
+	}
+}
+
+impl<'q, Q, K, V, S> ConcurrentGet<&'q Q> for DashMap<K, V, S>
+where
+	K: Hash + Eq + Borrow<Q>,
+	Q: Hash + Eq + ?Sized,
+	S: BuildHasher + Clone,
+{
+	type ItemRef<'a> = mapref::one::Ref<'a, K, V> where Self: 'a;
+
+	#[inline(always)]
+	fn get(&self, key: &'q Q) -> Option<mapref::one::Ref<'_, K, V>> {
+		self.get(key)
+	}
+
+	#[inline(always)]
+	fn contains(&self, key: &'q Q) -> bool {
+		self.contains_key(key)
+	}
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher + Clone> ConcurrentMapInsert<K> for DashMap<K, V, S> {
+	type Output = Option<V>;
+
+	#[inline(always)]
+	fn insert(&self, key: K, value: V) -> Option<V> {
+		self.insert(key, value)
+	}
+}
+
+impl<'q, Q, K, V, S> ConcurrentRemove<&'q Q> for DashMap<K, V, S>
+where
+	K: Hash + Eq + Borrow<Q>,
+	Q: Hash + Eq + ?Sized,
+	S: BuildHasher + Clone,
+{
+	#[inline(always)]
+	fn remove(&self, key: &'q Q) -> Option<V> {
+		self.remove(key).map(|(_, v)| v)
+	}
+}
+
+impl<T, S> Collection for DashSet<T, S> {
+	type Item = T;
+}
+
+impl<T: Hash + Eq, S: BuildHasher + Clone> Len for DashSet<T, S> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.is_empty()
+	}
+}
+
+impl<'q, Q, T, S> ConcurrentGet<&'q Q> for DashSet<T, S>
+where
+	T: Hash + Eq + Borrow<Q>,
+	Q: Hash + Eq + ?Sized,
+	S: BuildHasher + Clone,
+{
+	type ItemRef<'a> = setref::one::Ref<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn get(&self, key: &'q Q) -> Option<setref::one::Ref<'_, T>> {
+		self.get(key)
+	}
+
+	#[inline(always)]
+	fn contains(&self, key: &'q Q) -> bool {
+		self.contains(key)
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher + Clone> ConcurrentInsert for DashSet<T, S> {
+	type Output = bool;
+
+	#[inline(always)]
+	fn insert(&self, element: T) -> bool {
+		self.insert(element)
+	}
+}
+
+impl<'q, Q, T, S> ConcurrentRemove<&'q Q> for DashSet<T, S>
+where
+	T: Hash + Eq + Borrow<Q>,
+	Q: Hash + Eq + ?Sized,
+	S: BuildHasher + Clone,
+{
+	#[inline(always)]
+	fn remove(&self, key: &'q Q) -> Option<T> {
+		self.remove(key)
+	}
+}
@@ -0,0 +1,923 @@
+//! Forwarding implementations for references and smart pointers.
+//!
+//! The [`forward_ref!`] macro forwards the traits requiring only a shared
+//! reference to the collection, and the [`forward_mut!`] macro forwards the
+//! traits requiring a mutable reference.
+//! Both take the generic parameters of the implementation (introducing the
+//! pointed collection type `C`) followed by the pointer type.
+//!
+//! `Front`, `Back`, `FrontMut` and `BackMut` are not forwarded since
+//! they are already implemented for every pointer to a collection
+//! implementing `Get<usize>` (resp. `GetMut<usize>`) and `Len`.
+
+macro_rules! forward_ref {
+	([$($gen:tt)*] $ty:ty) => {
+		impl<$($gen)*> $crate::Collection for $ty
+		where
+			C: $crate::Collection,
+		{
+			type Item = C::Item;
+		}
+
+		impl<$($gen)*> $crate::CollectionRef for $ty
+		where
+			C: $crate::CollectionRef,
+		{
+			type ItemRef<'a> = C::ItemRef<'a> where Self: 'a;
+
+			#[inline(always)]
+			fn upcast_item_ref<'short, 'long: 'short>(
+				r: Self::ItemRef<'long>,
+			) -> Self::ItemRef<'short>
+			where
+				Self: 'long,
+			{
+				C::upcast_item_ref(r)
+			}
+		}
+
+		impl<$($gen)*> $crate::SimpleCollectionRef for $ty
+		where
+			C: $crate::SimpleCollectionRef,
+		{
+			#[inline(always)]
+			fn into_ref<'a>(r: Self::ItemRef<'a>) -> &'a Self::Item
+			where
+				Self: 'a,
+			{
+				C::into_ref(r)
+			}
+		}
+
+		impl<$($gen)*> $crate::Keyed for $ty
+		where
+			C: $crate::Keyed,
+		{
+			type Key = C::Key;
+		}
+
+		impl<$($gen)*> $crate::KeyedRef for $ty
+		where
+			C: $crate::KeyedRef,
+		{
+			type KeyRef<'a> = C::KeyRef<'a> where Self: 'a;
+
+			#[inline(always)]
+			fn upcast_key_ref<'short, 'long: 'short>(
+				r: Self::KeyRef<'long>,
+			) -> Self::KeyRef<'short>
+			where
+				Self: 'long,
+			{
+				C::upcast_key_ref(r)
+			}
+		}
+
+		impl<$($gen)*> $crate::SimpleKeyedRef for $ty
+		where
+			C: $crate::SimpleKeyedRef,
+		{
+			#[inline(always)]
+			fn into_ref<'a>(r: Self::KeyRef<'a>) -> &'a Self::Key
+			where
+				Self: 'a,
+			{
+				C::into_ref(r)
+			}
+		}
+
+		impl<$($gen)*> $crate::Len for $ty
+		where
+			C: $crate::Len,
+		{
+			#[inline(always)]
+			fn len(&self) -> usize {
+				C::len(self)
+			}
+
+			#[inline(always)]
+			fn is_empty(&self) -> bool {
+				C::is_empty(self)
+			}
+		}
+
+		impl<$($gen)*> $crate::Capacity for $ty
+		where
+			C: $crate::Capacity,
+		{
+			#[inline(always)]
+			fn capacity(&self) -> usize {
+				C::capacity(self)
+			}
+		}
+
+		impl<$($gen)*, Q> $crate::Get<Q> for $ty
+		where
+			C: $crate::Get<Q>,
+		{
+			#[inline(always)]
+			fn get(&self, key: Q) -> Option<Self::ItemRef<'_>> {
+				C::get(self, key)
+			}
+
+			#[inline(always)]
+			fn contains(&self, key: Q) -> bool {
+				C::contains(self, key)
+			}
+		}
+
+		impl<$($gen)*, Q> $crate::GetKeyValue<Q> for $ty
+		where
+			C: $crate::GetKeyValue<Q>,
+		{
+			#[inline(always)]
+			fn get_key_value(&self, key: Q) -> Option<(Self::KeyRef<'_>, Self::ItemRef<'_>)> {
+				C::get_key_value(self, key)
+			}
+		}
+
+		impl<$($gen)*> $crate::GetIndex for $ty
+		where
+			C: $crate::GetIndex,
+		{
+			#[inline(always)]
+			fn get_index(&self, index: usize) -> Option<(Self::KeyRef<'_>, Self::ItemRef<'_>)> {
+				C::get_index(self, index)
+			}
+		}
+
+		impl<$($gen)*> $crate::Contiguous for $ty
+		where
+			C: $crate::Contiguous,
+		{
+			#[inline(always)]
+			fn as_slice(&self) -> &[Self::Item] {
+				C::as_slice(self)
+			}
+		}
+
+		impl<$($gen)*> $crate::AsSlices for $ty
+		where
+			C: $crate::AsSlices,
+		{
+			#[inline(always)]
+			fn as_slices(&self) -> (&[Self::Item], &[Self::Item]) {
+				C::as_slices(self)
+			}
+		}
+
+		impl<$($gen)*> $crate::Iter for $ty
+		where
+			C: $crate::Iter,
+		{
+			type Iter<'a> = C::Iter<'a> where Self: 'a;
+
+			#[inline(always)]
+			fn iter(&self) -> Self::Iter<'_> {
+				C::iter(self)
+			}
+		}
+
+		impl<$($gen)*> $crate::MapIter for $ty
+		where
+			C: $crate::MapIter,
+		{
+			type Iter<'a> = C::Iter<'a> where Self: 'a;
+
+			#[inline(always)]
+			fn iter(&self) -> Self::Iter<'_> {
+				C::iter(self)
+			}
+		}
+
+		impl<$($gen)*> $crate::Keys for $ty
+		where
+			C: $crate::Keys,
+		{
+			type Keys<'a> = C::Keys<'a> where Self: 'a;
+
+			#[inline(always)]
+			fn keys(&self) -> Self::Keys<'_> {
+				C::keys(self)
+			}
+		}
+
+		impl<$($gen)*> $crate::Values for $ty
+		where
+			C: $crate::Values,
+		{
+			type Values<'a> = C::Values<'a> where Self: 'a;
+
+			#[inline(always)]
+			fn values(&self) -> Self::Values<'_> {
+				C::values(self)
+			}
+		}
+
+		impl<$($gen)*> $crate::IsSubset for $ty
+		where
+			C: $crate::IsSubset,
+		{
+			#[inline(always)]
+			fn is_subset(&self, other: &Self) -> bool {
+				C::is_subset(self, other)
+			}
+
+			#[inline(always)]
+			fn is_superset(&self, other: &Self) -> bool {
+				C::is_superset(self, other)
+			}
+		}
+
+		impl<$($gen)*> $crate::IsDisjoint for $ty
+		where
+			C: $crate::IsDisjoint,
+		{
+			#[inline(always)]
+			fn is_disjoint(&self, other: &Self) -> bool {
+				C::is_disjoint(self, other)
+			}
+		}
+
+		impl<$($gen)*> $crate::Ordered for $ty where C: $crate::Ordered {}
+
+		impl<$($gen)*, Q: ?Sized, R: ::core::ops::RangeBounds<Q>> $crate::Range<Q, R> for $ty
+		where
+			C: $crate::Range<Q, R>,
+		{
+			type Range<'a> = C::Range<'a> where Self: 'a;
+
+			#[inline(always)]
+			fn range(&self, range: R) -> Self::Range<'_> {
+				C::range(self, range)
+			}
+		}
+
+		impl<$($gen)*, Q: ?Sized, R: ::core::ops::RangeBounds<Q>> $crate::MapRange<Q, R> for $ty
+		where
+			C: $crate::MapRange<Q, R>,
+		{
+			type Range<'a> = C::Range<'a> where Self: 'a;
+
+			#[inline(always)]
+			fn range(&self, range: R) -> Self::Range<'_> {
+				C::range(self, range)
+			}
+		}
+
+		impl<$($gen)*> $crate::First for $ty
+		where
+			C: $crate::First,
+		{
+			#[inline(always)]
+			fn first(&self) -> Option<Self::ItemRef<'_>> {
+				C::first(self)
+			}
+		}
+
+		impl<$($gen)*> $crate::Last for $ty
+		where
+			C: $crate::Last,
+		{
+			#[inline(always)]
+			fn last(&self) -> Option<Self::ItemRef<'_>> {
+				C::last(self)
+			}
+		}
+
+		impl<$($gen)*> $crate::FirstKeyValue for $ty
+		where
+			C: $crate::FirstKeyValue,
+		{
+			#[inline(always)]
+			fn first_key_value(&self) -> Option<(Self::KeyRef<'_>, Self::ItemRef<'_>)> {
+				C::first_key_value(self)
+			}
+		}
+
+		impl<$($gen)*> $crate::LastKeyValue for $ty
+		where
+			C: $crate::LastKeyValue,
+		{
+			#[inline(always)]
+			fn last_key_value(&self) -> Option<(Self::KeyRef<'_>, Self::ItemRef<'_>)> {
+				C::last_key_value(self)
+			}
+		}
+
+		impl<$($gen)*, Q> $crate::Floor<Q> for $ty
+		where
+			C: $crate::Floor<Q>,
+		{
+			#[inline(always)]
+			fn floor(&self, key: Q) -> Option<Self::ItemRef<'_>> {
+				C::floor(self, key)
+			}
+		}
+
+		impl<$($gen)*, Q> $crate::Ceiling<Q> for $ty
+		where
+			C: $crate::Ceiling<Q>,
+		{
+			#[inline(always)]
+			fn ceiling(&self, key: Q) -> Option<Self::ItemRef<'_>> {
+				C::ceiling(self, key)
+			}
+		}
+
+		impl<$($gen)*, Q> $crate::FloorKeyValue<Q> for $ty
+		where
+			C: $crate::FloorKeyValue<Q>,
+		{
+			#[inline(always)]
+			fn floor_key_value(&self, key: Q) -> Option<(Self::KeyRef<'_>, Self::ItemRef<'_>)> {
+				C::floor_key_value(self, key)
+			}
+		}
+
+		impl<$($gen)*, Q> $crate::CeilingKeyValue<Q> for $ty
+		where
+			C: $crate::CeilingKeyValue<Q>,
+		{
+			#[inline(always)]
+			fn ceiling_key_value(&self, key: Q) -> Option<(Self::KeyRef<'_>, Self::ItemRef<'_>)> {
+				C::ceiling_key_value(self, key)
+			}
+		}
+
+		impl<$($gen)*> $crate::Peek for $ty
+		where
+			C: $crate::Peek,
+		{
+			#[inline(always)]
+			fn peek(&self) -> Option<Self::ItemRef<'_>> {
+				C::peek(self)
+			}
+		}
+
+		impl<$($gen)*> $crate::CursorApi for $ty
+		where
+			C: $crate::CursorApi,
+		{
+			type Cursor<'a> = C::Cursor<'a> where Self: 'a;
+
+			#[inline(always)]
+			fn cursor_front(&self) -> Self::Cursor<'_> {
+				C::cursor_front(self)
+			}
+
+			#[inline(always)]
+			fn cursor_back(&self) -> Self::Cursor<'_> {
+				C::cursor_back(self)
+			}
+		}
+	};
+}
+
+macro_rules! forward_mut {
+	([$($gen:tt)*] $ty:ty) => {
+		impl<$($gen)*> $crate::CollectionMut for $ty
+		where
+			C: $crate::CollectionMut,
+		{
+			type ItemMut<'a> = C::ItemMut<'a> where Self: 'a;
+
+			#[inline(always)]
+			fn upcast_item_mut<'short, 'long: 'short>(
+				r: Self::ItemMut<'long>,
+			) -> Self::ItemMut<'short>
+			where
+				Self: 'long,
+			{
+				C::upcast_item_mut(r)
+			}
+		}
+
+		impl<$($gen)*> $crate::SimpleCollectionMut for $ty
+		where
+			C: $crate::SimpleCollectionMut,
+		{
+			#[inline(always)]
+			fn into_mut<'a>(r: Self::ItemMut<'a>) -> &'a mut Self::Item
+			where
+				Self: 'a,
+			{
+				C::into_mut(r)
+			}
+		}
+
+		impl<$($gen)*> $crate::Reserve for $ty
+		where
+			C: $crate::Reserve,
+		{
+			#[inline(always)]
+			fn reserve(&mut self, additional: usize) {
+				C::reserve(self, additional)
+			}
+		}
+
+		impl<$($gen)*, Q> $crate::GetMut<Q> for $ty
+		where
+			C: $crate::GetMut<Q>,
+		{
+			#[inline(always)]
+			fn get_mut(&mut self, key: Q) -> Option<Self::ItemMut<'_>> {
+				C::get_mut(self, key)
+			}
+		}
+
+		impl<$($gen)*, Q> $crate::GetKeyValueMut<Q> for $ty
+		where
+			C: $crate::GetKeyValueMut<Q>,
+		{
+			#[inline(always)]
+			fn get_key_value_mut(
+				&mut self,
+				key: Q,
+			) -> Option<(Self::KeyRef<'_>, Self::ItemMut<'_>)> {
+				C::get_key_value_mut(self, key)
+			}
+		}
+
+		impl<$($gen)*> $crate::GetIndexMut for $ty
+		where
+			C: $crate::GetIndexMut,
+		{
+			#[inline(always)]
+			fn get_index_mut(
+				&mut self,
+				index: usize,
+			) -> Option<(Self::KeyRef<'_>, Self::ItemMut<'_>)> {
+				C::get_index_mut(self, index)
+			}
+		}
+
+		impl<$($gen)*> $crate::Insert for $ty
+		where
+			C: $crate::Insert,
+		{
+			type Output = C::Output;
+
+			#[inline(always)]
+			fn insert(&mut self, element: Self::Item) -> Self::Output {
+				C::insert(self, element)
+			}
+		}
+
+		impl<$($gen)*, K> $crate::MapInsert<K> for $ty
+		where
+			C: $crate::MapInsert<K>,
+		{
+			type Output = C::Output;
+
+			#[inline(always)]
+			fn insert(&mut self, key: K, value: Self::Item) -> Self::Output {
+				C::insert(self, key, value)
+			}
+		}
+
+		impl<$($gen)*> $crate::PushFront for $ty
+		where
+			C: $crate::PushFront,
+		{
+			type Output = C::Output;
+
+			#[inline(always)]
+			fn push_front(&mut self, element: Self::Item) -> Self::Output {
+				C::push_front(self, element)
+			}
+		}
+
+		impl<$($gen)*> $crate::PushBack for $ty
+		where
+			C: $crate::PushBack,
+		{
+			type Output = C::Output;
+
+			#[inline(always)]
+			fn push_back(&mut self, element: Self::Item) -> Self::Output {
+				C::push_back(self, element)
+			}
+		}
+
+		impl<$($gen)*, Q> $crate::Remove<Q> for $ty
+		where
+			C: $crate::Remove<Q>,
+		{
+			#[inline(always)]
+			fn remove(&mut self, key: Q) -> Option<Self::Item> {
+				C::remove(self, key)
+			}
+		}
+
+		impl<$($gen)*, Q> $crate::RemoveEntry<Q> for $ty
+		where
+			C: $crate::RemoveEntry<Q>,
+		{
+			#[inline(always)]
+			fn remove_entry(&mut self, key: Q) -> Option<(Self::Key, Self::Item)> {
+				C::remove_entry(self, key)
+			}
+		}
+
+		impl<$($gen)*> $crate::PopFront for $ty
+		where
+			C: $crate::PopFront,
+		{
+			#[inline(always)]
+			fn pop_front(&mut self) -> Option<Self::Item> {
+				C::pop_front(self)
+			}
+		}
+
+		impl<$($gen)*> $crate::PopBack for $ty
+		where
+			C: $crate::PopBack,
+		{
+			#[inline(always)]
+			fn pop_back(&mut self) -> Option<Self::Item> {
+				C::pop_back(self)
+			}
+		}
+
+		impl<$($gen)*> $crate::Clear for $ty
+		where
+			C: $crate::Clear,
+		{
+			#[inline(always)]
+			fn clear(&mut self) {
+				C::clear(self)
+			}
+		}
+
+		impl<$($gen)*> $crate::ContiguousMut for $ty
+		where
+			C: $crate::ContiguousMut,
+		{
+			#[inline(always)]
+			fn as_mut_slice(&mut self) -> &mut [Self::Item] {
+				C::as_mut_slice(self)
+			}
+		}
+
+		impl<$($gen)*> $crate::AsSlicesMut for $ty
+		where
+			C: $crate::AsSlicesMut,
+		{
+			#[inline(always)]
+			fn as_mut_slices(&mut self) -> (&mut [Self::Item], &mut [Self::Item]) {
+				C::as_mut_slices(self)
+			}
+		}
+
+		impl<$($gen)*> $crate::InsertAt for $ty
+		where
+			C: $crate::InsertAt,
+		{
+			#[inline(always)]
+			fn insert_at(&mut self, index: usize, element: Self::Item) {
+				C::insert_at(self, index, element)
+			}
+		}
+
+		impl<$($gen)*> $crate::SwapRemove for $ty
+		where
+			C: $crate::SwapRemove,
+		{
+			#[inline(always)]
+			fn swap_remove(&mut self, index: usize) -> Option<Self::Item> {
+				C::swap_remove(self, index)
+			}
+		}
+
+		impl<$($gen)*> $crate::Truncate for $ty
+		where
+			C: $crate::Truncate,
+		{
+			#[inline(always)]
+			fn truncate(&mut self, len: usize) {
+				C::truncate(self, len)
+			}
+		}
+
+		impl<$($gen)*> $crate::Resize for $ty
+		where
+			C: $crate::Resize,
+		{
+			#[inline(always)]
+			fn resize(&mut self, new_len: usize, value: Self::Item) {
+				C::resize(self, new_len, value)
+			}
+		}
+
+		impl<$($gen)*> $crate::ResizeWith for $ty
+		where
+			C: $crate::ResizeWith,
+		{
+			#[inline(always)]
+			fn resize_with<F>(&mut self, new_len: usize, f: F)
+			where
+				F: FnMut() -> Self::Item,
+			{
+				C::resize_with(self, new_len, f)
+			}
+		}
+
+		impl<$($gen)*> $crate::Append for $ty
+		where
+			C: $crate::Append,
+		{
+			#[inline(always)]
+			fn append(&mut self, other: &mut Self) {
+				C::append(self, other)
+			}
+		}
+
+		impl<$($gen)*> $crate::IterMut for $ty
+		where
+			C: $crate::IterMut,
+		{
+			type IterMut<'a> = C::IterMut<'a> where Self: 'a;
+
+			#[inline(always)]
+			fn iter_mut(&mut self) -> Self::IterMut<'_> {
+				C::iter_mut(self)
+			}
+		}
+
+		impl<$($gen)*> $crate::MapIterMut for $ty
+		where
+			C: $crate::MapIterMut,
+		{
+			type IterMut<'a> = C::IterMut<'a> where Self: 'a;
+
+			#[inline(always)]
+			fn iter_mut(&mut self) -> Self::IterMut<'_> {
+				C::iter_mut(self)
+			}
+		}
+
+		impl<$($gen)*> $crate::ValuesMut for $ty
+		where
+			C: $crate::ValuesMut,
+		{
+			type ValuesMut<'a> = C::ValuesMut<'a> where Self: 'a;
+
+			#[inline(always)]
+			fn values_mut(&mut self) -> Self::ValuesMut<'_> {
+				C::values_mut(self)
+			}
+		}
+
+		impl<$($gen)*> $crate::PopFirst for $ty
+		where
+			C: $crate::PopFirst,
+		{
+			#[inline(always)]
+			fn pop_first(&mut self) -> Option<Self::Item> {
+				C::pop_first(self)
+			}
+		}
+
+		impl<$($gen)*> $crate::PopLast for $ty
+		where
+			C: $crate::PopLast,
+		{
+			#[inline(always)]
+			fn pop_last(&mut self) -> Option<Self::Item> {
+				C::pop_last(self)
+			}
+		}
+
+		impl<$($gen)*> $crate::PopFirstKeyValue for $ty
+		where
+			C: $crate::PopFirstKeyValue,
+		{
+			#[inline(always)]
+			fn pop_first_key_value(&mut self) -> Option<(Self::Key, Self::Item)> {
+				C::pop_first_key_value(self)
+			}
+		}
+
+		impl<$($gen)*> $crate::PopLastKeyValue for $ty
+		where
+			C: $crate::PopLastKeyValue,
+		{
+			#[inline(always)]
+			fn pop_last_key_value(&mut self) -> Option<(Self::Key, Self::Item)> {
+				C::pop_last_key_value(self)
+			}
+		}
+
+		impl<$($gen)*> $crate::PeekMut for $ty
+		where
+			C: $crate::PeekMut,
+		{
+			#[inline(always)]
+			fn peek_mut(&mut self) -> Option<Self::ItemMut<'_>> {
+				C::peek_mut(self)
+			}
+		}
+
+		impl<$($gen)*> $crate::PushPriority for $ty
+		where
+			C: $crate::PushPriority,
+		{
+			type Output = C::Output;
+
+			#[inline(always)]
+			fn push(&mut self, element: Self::Item) -> Self::Output {
+				C::push(self, element)
+			}
+		}
+
+		impl<$($gen)*> $crate::PopMax for $ty
+		where
+			C: $crate::PopMax,
+		{
+			#[inline(always)]
+			fn pop_max(&mut self) -> Option<Self::Item> {
+				C::pop_max(self)
+			}
+		}
+
+		impl<$($gen)*> $crate::Drain for $ty
+		where
+			C: $crate::Drain,
+		{
+			type Drain<'a> = C::Drain<'a> where Self: 'a;
+
+			#[inline(always)]
+			fn drain(&mut self) -> Self::Drain<'_> {
+				C::drain(self)
+			}
+		}
+
+		impl<$($gen)*> $crate::MapDrain for $ty
+		where
+			C: $crate::MapDrain,
+		{
+			type Drain<'a> = C::Drain<'a> where Self: 'a;
+
+			#[inline(always)]
+			fn drain(&mut self) -> Self::Drain<'_> {
+				C::drain(self)
+			}
+		}
+
+		impl<$($gen)*, R: ::core::ops::RangeBounds<usize>> $crate::DrainRange<R> for $ty
+		where
+			C: $crate::DrainRange<R>,
+		{
+			type DrainRange<'a> = C::DrainRange<'a> where Self: 'a;
+
+			#[inline(always)]
+			fn drain_range(&mut self, range: R) -> Self::DrainRange<'_> {
+				C::drain_range(self, range)
+			}
+		}
+
+		impl<$($gen)*> $crate::Retain for $ty
+		where
+			C: $crate::Retain,
+		{
+			#[inline(always)]
+			fn retain<F>(&mut self, f: F)
+			where
+				F: FnMut(&Self::Item) -> bool,
+			{
+				C::retain(self, f)
+			}
+		}
+
+		impl<$($gen)*> $crate::MapRetain for $ty
+		where
+			C: $crate::MapRetain,
+		{
+			#[inline(always)]
+			fn retain<F>(&mut self, f: F)
+			where
+				F: FnMut(&Self::Key, &mut Self::Item) -> bool,
+			{
+				C::retain(self, f)
+			}
+		}
+
+		impl<$($gen)*> $crate::ExtendItems for $ty
+		where
+			C: $crate::ExtendItems,
+		{
+			#[inline(always)]
+			fn extend_items<I>(&mut self, items: I)
+			where
+				I: IntoIterator<Item = Self::Item>,
+			{
+				C::extend_items(self, items)
+			}
+		}
+
+		impl<$($gen)*, K> $crate::ExtendMap<K> for $ty
+		where
+			C: $crate::ExtendMap<K>,
+		{
+			#[inline(always)]
+			fn extend_map<I>(&mut self, items: I)
+			where
+				I: IntoIterator<Item = (K, Self::Item)>,
+			{
+				C::extend_map(self, items)
+			}
+		}
+
+		impl<$($gen)*, K> $crate::EntryApi<K> for $ty
+		where
+			C: $crate::EntryApi<K>,
+		{
+			type Occupied<'a> = C::Occupied<'a> where Self: 'a;
+
+			type Vacant<'a> = C::Vacant<'a> where Self: 'a;
+
+			#[inline(always)]
+			fn entry(&mut self, key: K) -> $crate::Entry<Self::Occupied<'_>, Self::Vacant<'_>> {
+				C::entry(self, key)
+			}
+		}
+
+		impl<$($gen)*> $crate::CursorMutApi for $ty
+		where
+			C: $crate::CursorMutApi,
+		{
+			type CursorMut<'a> = C::CursorMut<'a> where Self: 'a;
+
+			#[inline(always)]
+			fn cursor_front_mut(&mut self) -> Self::CursorMut<'_> {
+				C::cursor_front_mut(self)
+			}
+
+			#[inline(always)]
+			fn cursor_back_mut(&mut self) -> Self::CursorMut<'_> {
+				C::cursor_back_mut(self)
+			}
+		}
+
+		impl<$($gen)*> $crate::TryReserve for $ty
+		where
+			C: $crate::TryReserve,
+		{
+			type Error = C::Error;
+
+			#[inline(always)]
+			fn try_reserve(&mut self, additional: usize) -> Result<(), Self::Error> {
+				C::try_reserve(self, additional)
+			}
+		}
+
+		impl<$($gen)*> $crate::TryPushBack for $ty
+		where
+			C: $crate::TryPushBack,
+		{
+			type Error = C::Error;
+
+			#[inline(always)]
+			fn try_push_back(&mut self, element: Self::Item) -> Result<(), Self::Error> {
+				C::try_push_back(self, element)
+			}
+		}
+
+		impl<$($gen)*> $crate::TryInsert for $ty
+		where
+			C: $crate::TryInsert,
+		{
+			type Output = C::Output;
+
+			type Error = C::Error;
+
+			#[inline(always)]
+			fn try_insert(&mut self, element: Self::Item) -> Result<Self::Output, Self::Error> {
+				C::try_insert(self, element)
+			}
+		}
+
+		impl<$($gen)*, K> $crate::MapTryInsert<K> for $ty
+		where
+			C: $crate::MapTryInsert<K>,
+		{
+			type Output = C::Output;
+
+			type Error = C::Error;
+
+			#[inline(always)]
+			fn try_insert(
+				&mut self,
+				key: K,
+				value: Self::Item,
+			) -> Result<Self::Output, Self::Error> {
+				C::try_insert(self, key, value)
+			}
+		}
+	};
+}
+
+forward_ref!(['r, C: ?Sized] &'r C);
+forward_ref!(['r, C: ?Sized] &'r mut C);
+forward_mut!(['r, C: ?Sized] &'r mut C);
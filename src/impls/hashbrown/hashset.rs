@@ -0,0 +1,263 @@
+use crate::{
+	Append, Clear, Collection, CollectionMut, CollectionRef, Difference, Drain, ExtendItems, Get,
+	Insert, Intersection, IntoItems, IsDisjoint, IsSubset, Iter, Len, Remove, RemoveIn, Retain,
+	RetainIn, SimpleCollectionMut, SimpleCollectionRef, SymmetricDifference,
+	SymmetricDifferenceWith, TryInsert, TryReserve, TryWithCapacity, Union, UnionWith,
+	WithCapacity,
+};
+use core::hash::{BuildHasher, Hash};
+use hashbrown::{hash_set, Equivalent, HashSet, TryReserveError};
+
+impl<T, S> Collection for HashSet<T, S> {
+	type Item = T;
+}
+
+impl<T, S> CollectionRef for HashSet<T, S> {
+	type ItemRef<'a> = &'a T where Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<T, S> CollectionMut for HashSet<T, S> {
+	type ItemMut<'a> = &'a mut T where Self: 'a;
+
+	crate::covariant_item_mut!();
+}
+
+impl<T, S> SimpleCollectionRef for HashSet<T, S> {
+	crate::simple_collection_ref!();
+}
+
+impl<T, S> SimpleCollectionMut for HashSet<T, S> {
+	crate::simple_collection_mut!();
+}
+
+impl<T, S: Default> WithCapacity for HashSet<T, S> {
+	#[inline(always)]
+	fn with_capacity(capacity: usize) -> Self {
+		HashSet::with_capacity_and_hasher(capacity, S::default())
+	}
+}
+
+impl<T, S> Len for HashSet<T, S> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.is_empty()
+	}
+}
+
+impl<'a, Q, T: Hash + Eq, S: BuildHasher> Get<&'a Q> for HashSet<T, S>
+where
+	Q: Hash + Equivalent<T> + ?Sized,
+{
+	fn get(&self, value: &'a Q) -> Option<&T> {
+		self.get(value)
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> Insert for HashSet<T, S> {
+	type Output = bool;
+
+	#[inline(always)]
+	fn insert(&mut self, t: T) -> bool {
+		self.insert(t)
+	}
+}
+
+impl<'a, Q, T: Hash + Eq, S: BuildHasher> Remove<&'a Q> for HashSet<T, S>
+where
+	Q: Hash + Equivalent<T> + ?Sized,
+{
+	#[inline(always)]
+	fn remove(&mut self, t: &'a Q) -> Option<T> {
+		self.take(t)
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> Clear for HashSet<T, S> {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.clear()
+	}
+}
+
+impl<T, S> Iter for HashSet<T, S> {
+	type Iter<'a> = hash_set::Iter<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.iter()
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> Union for HashSet<T, S> {
+	type Union<'a> = hash_set::Union<'a, T, S> where Self: 'a;
+
+	#[inline(always)]
+	fn union<'a>(&'a self, other: &'a Self) -> Self::Union<'a> {
+		self.union(other)
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> UnionWith for HashSet<T, S> {
+	#[inline(always)]
+	fn union_with(&mut self, other: Self) {
+		self.extend(other)
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> Intersection for HashSet<T, S> {
+	type Intersection<'a> = hash_set::Intersection<'a, T, S> where Self: 'a;
+
+	#[inline(always)]
+	fn intersection<'a>(&'a self, other: &'a Self) -> Self::Intersection<'a> {
+		self.intersection(other)
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> RetainIn for HashSet<T, S> {
+	#[inline(always)]
+	fn retain_in(&mut self, other: &Self) {
+		self.retain(|t| other.contains(t))
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> Difference for HashSet<T, S> {
+	type Difference<'a> = hash_set::Difference<'a, T, S> where Self: 'a;
+
+	#[inline(always)]
+	fn difference<'a>(&'a self, other: &'a Self) -> Self::Difference<'a> {
+		self.difference(other)
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> RemoveIn for HashSet<T, S> {
+	fn remove_in(&mut self, other: &Self) {
+		if other.len() < self.len() {
+			for t in other {
+				self.remove(t);
+			}
+		} else {
+			self.retain(|t| !other.contains(t))
+		}
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> SymmetricDifference for HashSet<T, S> {
+	type SymmetricDifference<'a> = hash_set::SymmetricDifference<'a, T, S> where Self: 'a;
+
+	#[inline(always)]
+	fn symmetric_difference<'a>(&'a self, other: &'a Self) -> Self::SymmetricDifference<'a> {
+		self.symmetric_difference(other)
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> SymmetricDifferenceWith for HashSet<T, S> {
+	fn symmetric_difference_with(&mut self, other: Self) {
+		for t in other {
+			if !self.remove(&t) {
+				self.insert(t);
+			}
+		}
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> IsSubset for HashSet<T, S> {
+	#[inline(always)]
+	fn is_subset(&self, other: &Self) -> bool {
+		self.is_subset(other)
+	}
+
+	#[inline(always)]
+	fn is_superset(&self, other: &Self) -> bool {
+		self.is_superset(other)
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> IsDisjoint for HashSet<T, S> {
+	#[inline(always)]
+	fn is_disjoint(&self, other: &Self) -> bool {
+		self.is_disjoint(other)
+	}
+}
+
+impl<T, S> Drain for HashSet<T, S> {
+	type Drain<'a> = hash_set::Drain<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn drain(&mut self) -> Self::Drain<'_> {
+		self.drain()
+	}
+}
+
+impl<T, S> Retain for HashSet<T, S> {
+	#[inline(always)]
+	fn retain<F>(&mut self, f: F)
+	where
+		F: FnMut(&T) -> bool,
+	{
+		self.retain(f)
+	}
+}
+
+impl<T, S> IntoItems for HashSet<T, S> {
+	type IntoItems = hash_set::IntoIter<T>;
+
+	#[inline(always)]
+	fn into_items(self) -> Self::IntoItems {
+		self.into_iter()
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> ExtendItems for HashSet<T, S> {
+	#[inline(always)]
+	fn extend_items<I>(&mut self, items: I)
+	where
+		I: IntoIterator<Item = T>,
+	{
+		self.extend(items)
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> TryReserve for HashSet<T, S> {
+	type Error = TryReserveError;
+
+	#[inline(always)]
+	fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+		self.try_reserve(additional)
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher + Default> TryWithCapacity for HashSet<T, S> {
+	type Error = TryReserveError;
+
+	#[inline(always)]
+	fn try_with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+		let mut result = Self::with_hasher(S::default());
+		result.try_reserve(capacity)?;
+		Ok(result)
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> TryInsert for HashSet<T, S> {
+	type Output = bool;
+	type Error = TryReserveError;
+
+	#[inline(always)]
+	fn try_insert(&mut self, element: T) -> Result<bool, TryReserveError> {
+		self.try_reserve(1)?;
+		Ok(self.insert(element))
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> Append for HashSet<T, S> {
+	#[inline(always)]
+	fn append(&mut self, other: &mut Self) {
+		self.extend(other.drain())
+	}
+}
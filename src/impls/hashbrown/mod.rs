@@ -0,0 +1,2 @@
+mod hashmap;
+mod hashset;
@@ -0,0 +1,675 @@
+use crate::{
+	AsSlices, AsSlicesMut, Back, BackMut, Capacity, CapacityError, Clear, Collection, CollectionMut,
+	CollectionRef, Contiguous, ContiguousMut, Front, FrontMut, Get, GetKeyValue, GetMut, Insert,
+	IsDisjoint, IsSubset, Iter, IterMut, Keyed, KeyedRef, Len, MapInsert, MapIter, MapIterMut,
+	MapRetain, MapTryInsert, PopBack, PopFront, PushBack, PushFront, Remove, Retain,
+	SimpleCollectionMut, SimpleCollectionRef, SimpleKeyedRef, TryInsert, TryPushBack, TryReserve,
+	TryWithCapacity,
+};
+use core::{
+	borrow::Borrow,
+	hash::{BuildHasher, Hash},
+};
+use heapless::{Deque, IndexMap, IndexSet, Vec};
+
+impl<T, const N: usize> Collection for Vec<T, N> {
+	type Item = T;
+}
+
+impl<T, const N: usize> CollectionRef for Vec<T, N> {
+	type ItemRef<'a> = &'a T where Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<T, const N: usize> CollectionMut for Vec<T, N> {
+	type ItemMut<'a> = &'a mut T where Self: 'a;
+
+	crate::covariant_item_mut!();
+}
+
+impl<T, const N: usize> SimpleCollectionRef for Vec<T, N> {
+	crate::simple_collection_ref!();
+}
+
+impl<T, const N: usize> SimpleCollectionMut for Vec<T, N> {
+	crate::simple_collection_mut!();
+}
+
+impl<T, const N: usize> Len for Vec<T, N> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.as_slice().len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.is_empty()
+	}
+}
+
+impl<T, const N: usize> Capacity for Vec<T, N> {
+	#[inline(always)]
+	fn capacity(&self) -> usize {
+		N
+	}
+}
+
+impl<T, const N: usize> Get<usize> for Vec<T, N> {
+	#[inline(always)]
+	fn get(&self, index: usize) -> Option<&T> {
+		self.as_slice().get(index)
+	}
+}
+
+impl<T, const N: usize> GetMut<usize> for Vec<T, N> {
+	#[inline(always)]
+	fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+		self.as_mut_slice().get_mut(index)
+	}
+}
+
+impl<T, const N: usize> PushBack for Vec<T, N> {
+	type Output = Result<(), CapacityError<T>>;
+
+	#[inline(always)]
+	fn push_back(&mut self, t: T) -> Self::Output {
+		self.push(t).map_err(CapacityError::new)
+	}
+}
+
+impl<T, const N: usize> PopBack for Vec<T, N> {
+	#[inline(always)]
+	fn pop_back(&mut self) -> Option<T> {
+		self.pop()
+	}
+}
+
+impl<T, const N: usize> Remove<usize> for Vec<T, N> {
+	#[inline(always)]
+	fn remove(&mut self, index: usize) -> Option<T> {
+		if index < self.as_slice().len() {
+			Some(self.remove(index))
+		} else {
+			None
+		}
+	}
+}
+
+impl<T, const N: usize> Clear for Vec<T, N> {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.clear()
+	}
+}
+
+impl<T, const N: usize> Iter for Vec<T, N> {
+	type Iter<'a> = core::slice::Iter<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.as_slice().iter()
+	}
+}
+
+impl<T, const N: usize> IterMut for Vec<T, N> {
+	type IterMut<'a> = core::slice::IterMut<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn iter_mut(&mut self) -> Self::IterMut<'_> {
+		self.as_mut_slice().iter_mut()
+	}
+}
+
+impl<T, const N: usize> Retain for Vec<T, N> {
+	#[inline(always)]
+	fn retain<F>(&mut self, f: F)
+	where
+		F: FnMut(&T) -> bool,
+	{
+		self.retain(f)
+	}
+}
+
+impl<T, const N: usize> TryReserve for Vec<T, N> {
+	type Error = CapacityError;
+
+	#[inline(always)]
+	fn try_reserve(&mut self, additional: usize) -> Result<(), CapacityError> {
+		if additional <= N - self.as_slice().len() {
+			Ok(())
+		} else {
+			Err(CapacityError::new(()))
+		}
+	}
+}
+
+impl<T, const N: usize> TryWithCapacity for Vec<T, N> {
+	type Error = CapacityError;
+
+	#[inline(always)]
+	fn try_with_capacity(capacity: usize) -> Result<Self, CapacityError> {
+		if capacity <= N {
+			Ok(Self::new())
+		} else {
+			Err(CapacityError::new(()))
+		}
+	}
+}
+
+impl<T, const N: usize> TryPushBack for Vec<T, N> {
+	type Error = CapacityError<T>;
+
+	#[inline(always)]
+	fn try_push_back(&mut self, element: T) -> Result<(), CapacityError<T>> {
+		PushBack::push_back(self, element)
+	}
+}
+
+impl<T, const N: usize> Collection for Deque<T, N> {
+	type Item = T;
+}
+
+impl<T, const N: usize> CollectionRef for Deque<T, N> {
+	type ItemRef<'a> = &'a T where Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<T, const N: usize> CollectionMut for Deque<T, N> {
+	type ItemMut<'a> = &'a mut T where Self: 'a;
+
+	crate::covariant_item_mut!();
+}
+
+impl<T, const N: usize> SimpleCollectionRef for Deque<T, N> {
+	crate::simple_collection_ref!();
+}
+
+impl<T, const N: usize> SimpleCollectionMut for Deque<T, N> {
+	crate::simple_collection_mut!();
+}
+
+impl<T, const N: usize> Len for Deque<T, N> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.is_empty()
+	}
+}
+
+impl<T, const N: usize> Capacity for Deque<T, N> {
+	#[inline(always)]
+	fn capacity(&self) -> usize {
+		N
+	}
+}
+
+impl<T, const N: usize> Front for Deque<T, N> {
+	#[inline(always)]
+	fn front(&self) -> Option<&T> {
+		self.front()
+	}
+}
+
+impl<T, const N: usize> FrontMut for Deque<T, N> {
+	#[inline(always)]
+	fn front_mut(&mut self) -> Option<&mut T> {
+		self.front_mut()
+	}
+}
+
+impl<T, const N: usize> Back for Deque<T, N> {
+	#[inline(always)]
+	fn back(&self) -> Option<&T> {
+		self.back()
+	}
+}
+
+impl<T, const N: usize> BackMut for Deque<T, N> {
+	#[inline(always)]
+	fn back_mut(&mut self) -> Option<&mut T> {
+		self.back_mut()
+	}
+}
+
+impl<T, const N: usize> PushFront for Deque<T, N> {
+	type Output = Result<(), CapacityError<T>>;
+
+	#[inline(always)]
+	fn push_front(&mut self, t: T) -> Self::Output {
+		self.push_front(t).map_err(CapacityError::new)
+	}
+}
+
+impl<T, const N: usize> PushBack for Deque<T, N> {
+	type Output = Result<(), CapacityError<T>>;
+
+	#[inline(always)]
+	fn push_back(&mut self, t: T) -> Self::Output {
+		self.push_back(t).map_err(CapacityError::new)
+	}
+}
+
+impl<T, const N: usize> PopFront for Deque<T, N> {
+	#[inline(always)]
+	fn pop_front(&mut self) -> Option<T> {
+		self.pop_front()
+	}
+}
+
+impl<T, const N: usize> PopBack for Deque<T, N> {
+	#[inline(always)]
+	fn pop_back(&mut self) -> Option<T> {
+		self.pop_back()
+	}
+}
+
+impl<T, const N: usize> Clear for Deque<T, N> {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.clear()
+	}
+}
+
+impl<T, const N: usize> Iter for Deque<T, N> {
+	type Iter<'a> = core::iter::Chain<
+		core::slice::Iter<'a, T>,
+		core::slice::Iter<'a, T>,
+	> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		let (a, b) = self.as_slices();
+		a.iter().chain(b)
+	}
+}
+
+impl<T, const N: usize> IterMut for Deque<T, N> {
+	type IterMut<'a> = core::iter::Chain<
+		core::slice::IterMut<'a, T>,
+		core::slice::IterMut<'a, T>,
+	> where Self: 'a;
+
+	#[inline(always)]
+	fn iter_mut(&mut self) -> Self::IterMut<'_> {
+		let (a, b) = self.as_mut_slices();
+		a.iter_mut().chain(b)
+	}
+}
+
+impl<T, const N: usize> TryReserve for Deque<T, N> {
+	type Error = CapacityError;
+
+	#[inline(always)]
+	fn try_reserve(&mut self, additional: usize) -> Result<(), CapacityError> {
+		if additional <= N - self.len() {
+			Ok(())
+		} else {
+			Err(CapacityError::new(()))
+		}
+	}
+}
+
+impl<T, const N: usize> TryWithCapacity for Deque<T, N> {
+	type Error = CapacityError;
+
+	#[inline(always)]
+	fn try_with_capacity(capacity: usize) -> Result<Self, CapacityError> {
+		if capacity <= N {
+			Ok(Self::new())
+		} else {
+			Err(CapacityError::new(()))
+		}
+	}
+}
+
+impl<T, const N: usize> TryPushBack for Deque<T, N> {
+	type Error = CapacityError<T>;
+
+	#[inline(always)]
+	fn try_push_back(&mut self, element: T) -> Result<(), CapacityError<T>> {
+		PushBack::push_back(self, element)
+	}
+}
+
+impl<K, V, S, const N: usize> Collection for IndexMap<K, V, S, N> {
+	type Item = V;
+}
+
+impl<K, V, S, const N: usize> CollectionRef for IndexMap<K, V, S, N> {
+	type ItemRef<'a> = &'a V where Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<K, V, S, const N: usize> CollectionMut for IndexMap<K, V, S, N> {
+	type ItemMut<'a> = &'a mut V where Self: 'a;
+
+	crate::covariant_item_mut!();
+}
+
+impl<K, V, S, const N: usize> SimpleCollectionRef for IndexMap<K, V, S, N> {
+	crate::simple_collection_ref!();
+}
+
+impl<K, V, S, const N: usize> SimpleCollectionMut for IndexMap<K, V, S, N> {
+	crate::simple_collection_mut!();
+}
+
+impl<K, V, S, const N: usize> Keyed for IndexMap<K, V, S, N> {
+	type Key = K;
+}
+
+impl<K, V, S, const N: usize> KeyedRef for IndexMap<K, V, S, N> {
+	type KeyRef<'a> = &'a K where Self: 'a;
+
+	crate::covariant_key_ref!();
+}
+
+impl<K, V, S, const N: usize> SimpleKeyedRef for IndexMap<K, V, S, N> {
+	crate::simple_keyed_ref!();
+}
+
+impl<K, V, S, const N: usize> Len for IndexMap<K, V, S, N> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.is_empty()
+	}
+}
+
+impl<K, V, S, const N: usize> Capacity for IndexMap<K, V, S, N> {
+	#[inline(always)]
+	fn capacity(&self) -> usize {
+		N
+	}
+}
+
+impl<'a, Q, K, V, S, const N: usize> Get<&'a Q> for IndexMap<K, V, S, N>
+where
+	K: Borrow<Q> + Hash + Eq,
+	Q: Hash + Eq + ?Sized,
+	S: BuildHasher,
+{
+	#[inline(always)]
+	fn get(&self, key: &'a Q) -> Option<&V> {
+		self.get(key)
+	}
+}
+
+impl<'a, Q, K, V, S, const N: usize> GetMut<&'a Q> for IndexMap<K, V, S, N>
+where
+	K: Borrow<Q> + Hash + Eq,
+	Q: Hash + Eq + ?Sized,
+	S: BuildHasher,
+{
+	#[inline(always)]
+	fn get_mut(&mut self, key: &'a Q) -> Option<&mut V> {
+		self.get_mut(key)
+	}
+}
+
+impl<'a, Q, K, V, S, const N: usize> GetKeyValue<&'a Q> for IndexMap<K, V, S, N>
+where
+	K: Borrow<Q> + Hash + Eq,
+	Q: Hash + Eq + ?Sized,
+	S: BuildHasher,
+{
+	/// `heapless` does not provide a way to get the stored key,
+	/// so it is searched linearly once the key is known to be present.
+	#[inline(always)]
+	fn get_key_value(&self, key: &'a Q) -> Option<(&K, &V)> {
+		if self.contains_key(key) {
+			self.iter().find(|(k, _)| (*k).borrow() == key)
+		} else {
+			None
+		}
+	}
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher, const N: usize> MapInsert<K> for IndexMap<K, V, S, N> {
+	type Output = Result<Option<V>, CapacityError<(K, V)>>;
+
+	#[inline(always)]
+	fn insert(&mut self, key: K, value: V) -> Self::Output {
+		self.insert(key, value).map_err(CapacityError::new)
+	}
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher, const N: usize> MapTryInsert<K> for IndexMap<K, V, S, N> {
+	type Output = Option<V>;
+	type Error = CapacityError<(K, V)>;
+
+	#[inline(always)]
+	fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, CapacityError<(K, V)>> {
+		MapInsert::insert(self, key, value)
+	}
+}
+
+impl<'a, Q, K, V, S, const N: usize> Remove<&'a Q> for IndexMap<K, V, S, N>
+where
+	K: Borrow<Q> + Hash + Eq,
+	Q: Hash + Eq + ?Sized,
+	S: BuildHasher,
+{
+	#[inline(always)]
+	fn remove(&mut self, key: &'a Q) -> Option<V> {
+		self.remove(key)
+	}
+}
+
+impl<K, V, S, const N: usize> Clear for IndexMap<K, V, S, N> {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.clear()
+	}
+}
+
+impl<K, V, S, const N: usize> Iter for IndexMap<K, V, S, N> {
+	type Iter<'a> = heapless::IndexMapValues<'a, K, V> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.values()
+	}
+}
+
+impl<K, V, S, const N: usize> MapIter for IndexMap<K, V, S, N> {
+	type Iter<'a> = heapless::IndexMapIter<'a, K, V> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.iter()
+	}
+}
+
+impl<K, V, S, const N: usize> MapIterMut for IndexMap<K, V, S, N> {
+	type IterMut<'a> = heapless::IndexMapIterMut<'a, K, V> where Self: 'a;
+
+	#[inline(always)]
+	fn iter_mut(&mut self) -> Self::IterMut<'_> {
+		self.iter_mut()
+	}
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher, const N: usize> Retain for IndexMap<K, V, S, N> {
+	#[inline(always)]
+	fn retain<F>(&mut self, mut f: F)
+	where
+		F: FnMut(&V) -> bool,
+	{
+		self.retain(|_, v| f(v))
+	}
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher, const N: usize> MapRetain for IndexMap<K, V, S, N> {
+	#[inline(always)]
+	fn retain<F>(&mut self, f: F)
+	where
+		F: FnMut(&K, &mut V) -> bool,
+	{
+		self.retain(f)
+	}
+}
+
+impl<T, S, const N: usize> Collection for IndexSet<T, S, N> {
+	type Item = T;
+}
+
+impl<T, S, const N: usize> CollectionRef for IndexSet<T, S, N> {
+	type ItemRef<'a> = &'a T where Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<T, S, const N: usize> CollectionMut for IndexSet<T, S, N> {
+	type ItemMut<'a> = &'a mut T where Self: 'a;
+
+	crate::covariant_item_mut!();
+}
+
+impl<T, S, const N: usize> SimpleCollectionRef for IndexSet<T, S, N> {
+	crate::simple_collection_ref!();
+}
+
+impl<T, S, const N: usize> SimpleCollectionMut for IndexSet<T, S, N> {
+	crate::simple_collection_mut!();
+}
+
+impl<T, S, const N: usize> Len for IndexSet<T, S, N> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.is_empty()
+	}
+}
+
+impl<T, S, const N: usize> Capacity for IndexSet<T, S, N> {
+	#[inline(always)]
+	fn capacity(&self) -> usize {
+		N
+	}
+}
+
+impl<'a, Q, T, S, const N: usize> Get<&'a Q> for IndexSet<T, S, N>
+where
+	T: Borrow<Q> + Hash + Eq,
+	Q: Hash + Eq + ?Sized,
+	S: BuildHasher,
+{
+	/// `heapless` does not provide a way to get the stored value,
+	/// so it is searched linearly once it is known to be present.
+	#[inline(always)]
+	fn get(&self, value: &'a Q) -> Option<&T> {
+		if self.contains(value) {
+			self.iter().find(|t| (*t).borrow() == value)
+		} else {
+			None
+		}
+	}
+
+	#[inline(always)]
+	fn contains(&self, value: &'a Q) -> bool {
+		self.contains(value)
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher, const N: usize> Insert for IndexSet<T, S, N> {
+	type Output = Result<bool, CapacityError<T>>;
+
+	#[inline(always)]
+	fn insert(&mut self, t: T) -> Self::Output {
+		self.insert(t).map_err(CapacityError::new)
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher, const N: usize> TryInsert for IndexSet<T, S, N> {
+	type Output = bool;
+	type Error = CapacityError<T>;
+
+	#[inline(always)]
+	fn try_insert(&mut self, t: T) -> Result<bool, CapacityError<T>> {
+		Insert::insert(self, t)
+	}
+}
+
+impl<T, S, const N: usize> Clear for IndexSet<T, S, N> {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.clear()
+	}
+}
+
+impl<T, S, const N: usize> Iter for IndexSet<T, S, N> {
+	type Iter<'a> = heapless::IndexSetIter<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.iter()
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher, const N: usize> Retain for IndexSet<T, S, N> {
+	#[inline(always)]
+	fn retain<F>(&mut self, f: F)
+	where
+		F: FnMut(&T) -> bool,
+	{
+		self.retain(f)
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher, const N: usize> IsSubset for IndexSet<T, S, N> {
+	#[inline(always)]
+	fn is_subset(&self, other: &Self) -> bool {
+		self.is_subset(other)
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher, const N: usize> IsDisjoint for IndexSet<T, S, N> {
+	#[inline(always)]
+	fn is_disjoint(&self, other: &Self) -> bool {
+		self.is_disjoint(other)
+	}
+}
+
+impl<T, const N: usize> Contiguous for Vec<T, N> {
+	#[inline(always)]
+	fn as_slice(&self) -> &[T] {
+		self.as_slice()
+	}
+}
+
+impl<T, const N: usize> ContiguousMut for Vec<T, N> {
+	#[inline(always)]
+	fn as_mut_slice(&mut self) -> &mut [T] {
+		self.as_mut_slice()
+	}
+}
+
+impl<T, const N: usize> AsSlices for Deque<T, N> {
+	#[inline(always)]
+	fn as_slices(&self) -> (&[T], &[T]) {
+		self.as_slices()
+	}
+}
+
+impl<T, const N: usize> AsSlicesMut for Deque<T, N> {
+	#[inline(always)]
+	fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+		self.as_mut_slices()
+	}
+}
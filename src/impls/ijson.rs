@@ -1,8 +1,13 @@
 use crate::{
-	Capacity, Clear, Collection, CollectionMut, CollectionRef, Get, GetKeyValue, GetKeyValueMut,
-	GetMut, Iter, IterMut, Keyed, KeyedRef, Len, MapInsert, MapIter, MapIterMut, PopBack, PushBack,
-	Remove, Reserve, SimpleCollectionMut, SimpleCollectionRef, SimpleKeyedRef, WithCapacity,
+	Append, Capacity, Clear, Collection, CollectionMut, CollectionRef, Contiguous, ContiguousMut,
+	Drain, DrainRange, Entry, EntryApi, ExtendItems, ExtendMap, Get, GetKeyValue, GetKeyValueMut,
+	GetMut, InsertAt, IntoItems, IntoKeys, IntoValues, Iter, IterMut, Keyed, KeyedRef, Keys, Len,
+	MapDrain, MapInsert, MapIntoItems, MapIter, MapIterMut, MapRetain, OccupiedEntry, PopBack,
+	PushBack, Remove, RemoveEntry, Reserve, Resize, ResizeWith, Retain, SimpleCollectionMut,
+	SimpleCollectionRef, SimpleKeyedRef, SplitOff, SwapRemove, Truncate, VacantEntry, Values,
+	ValuesMut, WithCapacity,
 };
+use core::ops::{Bound, RangeBounds};
 use ijson::{IArray, IObject, IString, IValue};
 
 impl Collection for IObject {
@@ -43,6 +48,13 @@ impl SimpleKeyedRef for IObject {
 	crate::simple_keyed_ref!();
 }
 
+impl WithCapacity for IObject {
+	#[inline(always)]
+	fn with_capacity(capacity: usize) -> Self {
+		Self::with_capacity(capacity)
+	}
+}
+
 impl Len for IObject {
 	#[inline(always)]
 	fn len(&self) -> usize {
@@ -110,6 +122,16 @@ impl MapInsert<IString> for IObject {
 	}
 }
 
+impl ExtendMap<IString> for IObject {
+	#[inline(always)]
+	fn extend_map<I>(&mut self, items: I)
+	where
+		I: IntoIterator<Item = (IString, IValue)>,
+	{
+		self.extend(items)
+	}
+}
+
 impl<Q: ijson::object::ObjectIndex> Remove<Q> for IObject {
 	#[inline(always)]
 	fn remove(&mut self, key: Q) -> Option<IValue> {
@@ -117,6 +139,13 @@ impl<Q: ijson::object::ObjectIndex> Remove<Q> for IObject {
 	}
 }
 
+impl<Q: ijson::object::ObjectIndex> RemoveEntry<Q> for IObject {
+	#[inline(always)]
+	fn remove_entry(&mut self, key: Q) -> Option<(IString, IValue)> {
+		self.remove_entry(key)
+	}
+}
+
 impl Clear for IObject {
 	#[inline(always)]
 	fn clear(&mut self) {
@@ -124,6 +153,130 @@ impl Clear for IObject {
 	}
 }
 
+impl<K: Into<IString>> EntryApi<K> for IObject {
+	type Occupied<'a> = ijson::object::OccupiedEntry<'a>;
+	type Vacant<'a> = ijson::object::VacantEntry<'a>;
+
+	#[inline(always)]
+	fn entry(&mut self, key: K) -> Entry<Self::Occupied<'_>, Self::Vacant<'_>> {
+		match self.entry(key) {
+			ijson::object::Entry::Occupied(o) => Entry::Occupied(o),
+			ijson::object::Entry::Vacant(v) => Entry::Vacant(v),
+		}
+	}
+}
+
+impl<'a> OccupiedEntry<'a> for ijson::object::OccupiedEntry<'a> {
+	type Key = IString;
+	type Item = IValue;
+
+	#[inline(always)]
+	fn key(&self) -> &IString {
+		self.key()
+	}
+
+	#[inline(always)]
+	fn get(&self) -> &IValue {
+		self.get()
+	}
+
+	#[inline(always)]
+	fn get_mut(&mut self) -> &mut IValue {
+		self.get_mut()
+	}
+
+	#[inline(always)]
+	fn into_mut(self) -> &'a mut IValue {
+		self.into_mut()
+	}
+
+	#[inline(always)]
+	fn insert(&mut self, value: IValue) -> IValue {
+		self.insert(value)
+	}
+
+	#[inline(always)]
+	fn remove_entry(self) -> (IString, IValue) {
+		self.remove_entry()
+	}
+
+	#[inline(always)]
+	fn remove(self) -> IValue {
+		self.remove()
+	}
+}
+
+impl<'a> VacantEntry<'a> for ijson::object::VacantEntry<'a> {
+	type Key = IString;
+	type Item = IValue;
+
+	#[inline(always)]
+	fn key(&self) -> &IString {
+		self.key()
+	}
+
+	#[inline(always)]
+	fn insert(self, value: IValue) -> &'a mut IValue {
+		self.insert(value)
+	}
+}
+
+impl Drain for IObject {
+	type Drain<'a> = core::iter::Map<ijson::object::IntoIter, fn((IString, IValue)) -> IValue>;
+
+	#[inline(always)]
+	fn drain(&mut self) -> Self::Drain<'_> {
+		core::mem::take(self).into_iter().map(|(_, v)| v)
+	}
+}
+
+impl MapDrain for IObject {
+	type Drain<'a> = ijson::object::IntoIter;
+
+	#[inline(always)]
+	fn drain(&mut self) -> Self::Drain<'_> {
+		core::mem::take(self).into_iter()
+	}
+}
+
+impl Retain for IObject {
+	#[inline(always)]
+	fn retain<F>(&mut self, mut f: F)
+	where
+		F: FnMut(&IValue) -> bool,
+	{
+		self.retain(|_, v| f(v))
+	}
+}
+
+impl MapRetain for IObject {
+	#[inline(always)]
+	fn retain<F>(&mut self, f: F)
+	where
+		F: FnMut(&IString, &mut IValue) -> bool,
+	{
+		self.retain(f)
+	}
+}
+
+impl IntoItems for IObject {
+	type IntoItems = core::iter::Map<ijson::object::IntoIter, fn((IString, IValue)) -> IValue>;
+
+	#[inline(always)]
+	fn into_items(self) -> Self::IntoItems {
+		self.into_iter().map(|(_, v)| v)
+	}
+}
+
+impl MapIntoItems for IObject {
+	type IntoItems = ijson::object::IntoIter;
+
+	#[inline(always)]
+	fn into_items(self) -> Self::IntoItems {
+		self.into_iter()
+	}
+}
+
 impl Collection for IArray {
 	type Item = IValue;
 }
@@ -238,3 +391,210 @@ impl IterMut for IArray {
 		self.as_mut_slice().iter_mut()
 	}
 }
+
+impl Drain for IArray {
+	type Drain<'a> = ijson::array::IntoIter;
+
+	#[inline(always)]
+	fn drain(&mut self) -> Self::Drain<'_> {
+		core::mem::take(self).into_iter()
+	}
+}
+
+impl<R: RangeBounds<usize>> DrainRange<R> for IArray {
+	type DrainRange<'a> = std::vec::IntoIter<IValue>;
+
+	fn drain_range(&mut self, range: R) -> Self::DrainRange<'_> {
+		let start = match range.start_bound() {
+			Bound::Included(&i) => i,
+			Bound::Excluded(&i) => i
+				.checked_add(1)
+				.expect("attempted to drain from after maximum usize"),
+			Bound::Unbounded => 0,
+		};
+		let end = match range.end_bound() {
+			Bound::Included(&i) => i
+				.checked_add(1)
+				.expect("attempted to drain up to maximum usize"),
+			Bound::Excluded(&i) => i,
+			Bound::Unbounded => self.len(),
+		};
+
+		assert!(start <= end, "drain range start is greater than its end");
+		assert!(end <= self.len(), "drain range end is out of bounds");
+
+		// Move the drained items at the end of the array, then pop them.
+		self.as_mut_slice()[start..].rotate_left(end - start);
+		let mut drained: Vec<IValue> = (start..end).filter_map(|_| self.pop()).collect();
+		drained.reverse();
+		drained.into_iter()
+	}
+}
+
+impl Retain for IArray {
+	fn retain<F>(&mut self, mut f: F)
+	where
+		F: FnMut(&IValue) -> bool,
+	{
+		let items = self.as_mut_slice();
+		let mut len = 0;
+		for i in 0..items.len() {
+			if f(&items[i]) {
+				items.swap(len, i);
+				len += 1;
+			}
+		}
+
+		self.truncate(len)
+	}
+}
+
+impl IntoItems for IArray {
+	type IntoItems = ijson::array::IntoIter;
+
+	#[inline(always)]
+	fn into_items(self) -> Self::IntoItems {
+		self.into_iter()
+	}
+}
+
+impl ExtendItems for IArray {
+	#[inline(always)]
+	fn extend_items<I>(&mut self, items: I)
+	where
+		I: IntoIterator<Item = IValue>,
+	{
+		self.extend(items)
+	}
+}
+
+impl Append for IObject {
+	#[inline(always)]
+	fn append(&mut self, other: &mut Self) {
+		self.extend(core::mem::take(other))
+	}
+}
+
+impl InsertAt for IArray {
+	#[inline(always)]
+	fn insert_at(&mut self, index: usize, element: IValue) {
+		self.insert(index, element)
+	}
+}
+
+impl SwapRemove for IArray {
+	#[inline(always)]
+	fn swap_remove(&mut self, index: usize) -> Option<IValue> {
+		self.swap_remove(index)
+	}
+}
+
+impl Truncate for IArray {
+	#[inline(always)]
+	fn truncate(&mut self, len: usize) {
+		self.truncate(len)
+	}
+}
+
+impl Resize for IArray {
+	#[inline(always)]
+	fn resize(&mut self, new_len: usize, value: IValue) {
+		ResizeWith::resize_with(self, new_len, || value.clone())
+	}
+}
+
+impl ResizeWith for IArray {
+	#[inline(always)]
+	fn resize_with<F>(&mut self, new_len: usize, f: F)
+	where
+		F: FnMut() -> IValue,
+	{
+		match new_len.checked_sub(self.len()) {
+			Some(additional) => self.extend(core::iter::repeat_with(f).take(additional)),
+			None => self.truncate(new_len),
+		}
+	}
+}
+
+impl SplitOff for IArray {
+	fn split_off(&mut self, at: usize) -> Self {
+		assert!(at <= self.len(), "split index is out of bounds");
+		let tail: Vec<IValue> = (at..self.len()).filter_map(|_| self.pop()).collect();
+		tail.into_iter().rev().collect()
+	}
+}
+
+impl Append for IArray {
+	#[inline(always)]
+	fn append(&mut self, other: &mut Self) {
+		self.extend(core::mem::take(other))
+	}
+}
+
+impl Contiguous for IArray {
+	#[inline(always)]
+	fn as_slice(&self) -> &[IValue] {
+		self.as_slice()
+	}
+}
+
+impl ContiguousMut for IArray {
+	#[inline(always)]
+	fn as_mut_slice(&mut self) -> &mut [IValue] {
+		self.as_mut_slice()
+	}
+}
+
+impl Keys for IObject {
+	type Keys<'a> = core::iter::Map<
+		ijson::object::Iter<'a>,
+		fn((&'a IString, &'a IValue)) -> &'a IString,
+	> where Self: 'a;
+
+	#[inline(always)]
+	fn keys(&self) -> Self::Keys<'_> {
+		self.iter().map(|(k, _)| k)
+	}
+}
+
+impl Values for IObject {
+	type Values<'a> = core::iter::Map<
+		ijson::object::Iter<'a>,
+		fn((&'a IString, &'a IValue)) -> &'a IValue,
+	> where Self: 'a;
+
+	#[inline(always)]
+	fn values(&self) -> Self::Values<'_> {
+		self.iter().map(|(_, v)| v)
+	}
+}
+
+impl ValuesMut for IObject {
+	type ValuesMut<'a> = core::iter::Map<
+		ijson::object::IterMut<'a>,
+		fn((&'a IString, &'a mut IValue)) -> &'a mut IValue,
+	> where Self: 'a;
+
+	#[inline(always)]
+	fn values_mut(&mut self) -> Self::ValuesMut<'_> {
+		self.iter_mut().map(|(_, v)| v)
+	}
+}
+
+impl IntoKeys for IObject {
+	type IntoKeys = core::iter::Map<ijson::object::IntoIter, fn((IString, IValue)) -> IString>;
+
+	#[inline(always)]
+	fn into_keys(self) -> Self::IntoKeys {
+		self.into_iter().map(|(k, _)| k)
+	}
+}
+
+impl IntoValues for IObject {
+	type IntoValues = core::iter::Map<ijson::object::IntoIter, fn((IString, IValue)) -> IValue>;
+
+	#[inline(always)]
+	fn into_values(self) -> Self::IntoValues {
+		self.into_iter().map(|(_, v)| v)
+	}
+}
@@ -0,0 +1,680 @@
+use crate::{
+	Append, Capacity, Clear, Collection, CollectionMut, CollectionRef, Difference, Drain,
+	ExtendItems, ExtendMap, Get, GetIndex, GetIndexMut, GetKeyValue, GetKeyValueMut, GetMut,
+	Insert, Intersection, IntoItems, IntoKeys, IntoValues, IsDisjoint, IsSubset, Iter, Keyed,
+	KeyedRef, Keys, Len, MapDrain, MapInsert, MapIntoItems, MapIter, MapIterMut, MapRetain, Remove,
+	RemoveEntry, RemoveIn, Reserve, Retain, RetainIn, Shifting, SimpleCollectionMut,
+	SimpleCollectionRef, SimpleKeyedRef, SplitOff, Swapping, SymmetricDifference,
+	SymmetricDifferenceWith, Truncate, TryReserve, Union, UnionWith, Values, ValuesMut,
+	WithCapacity,
+};
+use core::hash::{BuildHasher, Hash};
+use indexmap::{map, set, Equivalent, IndexMap, IndexSet, TryReserveError};
+
+impl<K, V, S> Collection for IndexMap<K, V, S> {
+	type Item = V;
+}
+
+impl<K, V, S> CollectionRef for IndexMap<K, V, S> {
+	type ItemRef<'a> = &'a V where Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<K, V, S> CollectionMut for IndexMap<K, V, S> {
+	type ItemMut<'a> = &'a mut V where Self: 'a;
+
+	crate::covariant_item_mut!();
+}
+
+impl<K, V, S> SimpleCollectionRef for IndexMap<K, V, S> {
+	crate::simple_collection_ref!();
+}
+
+impl<K, V, S> SimpleCollectionMut for IndexMap<K, V, S> {
+	crate::simple_collection_mut!();
+}
+
+impl<K, V, S> Keyed for IndexMap<K, V, S> {
+	type Key = K;
+}
+
+impl<K, V, S> KeyedRef for IndexMap<K, V, S> {
+	type KeyRef<'a> = &'a K where Self: 'a;
+
+	crate::covariant_key_ref!();
+}
+
+impl<K, V, S> SimpleKeyedRef for IndexMap<K, V, S> {
+	crate::simple_keyed_ref!();
+}
+
+impl<K, V, S: Default> WithCapacity for IndexMap<K, V, S> {
+	#[inline(always)]
+	fn with_capacity(capacity: usize) -> Self {
+		IndexMap::with_capacity_and_hasher(capacity, S::default())
+	}
+}
+
+impl<K, V, S> Len for IndexMap<K, V, S> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.is_empty()
+	}
+}
+
+impl<K, V, S> Capacity for IndexMap<K, V, S> {
+	#[inline(always)]
+	fn capacity(&self) -> usize {
+		self.capacity()
+	}
+}
+
+impl<K, V, S> Reserve for IndexMap<K, V, S> {
+	#[inline(always)]
+	fn reserve(&mut self, additional: usize) {
+		self.reserve(additional)
+	}
+}
+
+impl<'a, Q, K, V, S> Get<&'a Q> for IndexMap<K, V, S>
+where
+	Q: Hash + Equivalent<K> + ?Sized,
+	S: BuildHasher,
+{
+	#[inline(always)]
+	fn get(&self, key: &'a Q) -> Option<&V> {
+		self.get(key)
+	}
+}
+
+impl<'a, Q, K, V, S> GetMut<&'a Q> for IndexMap<K, V, S>
+where
+	Q: Hash + Equivalent<K> + ?Sized,
+	S: BuildHasher,
+{
+	#[inline(always)]
+	fn get_mut(&mut self, key: &'a Q) -> Option<&mut V> {
+		self.get_mut(key)
+	}
+}
+
+impl<'a, Q, K, V, S> GetKeyValueMut<&'a Q> for IndexMap<K, V, S>
+where
+	Q: Hash + Equivalent<K> + ?Sized,
+	S: BuildHasher,
+{
+	#[inline(always)]
+	fn get_key_value_mut(&mut self, key: &'a Q) -> Option<(&K, &mut V)> {
+		self.get_full_mut(key).map(|(_, k, v)| (k, v))
+	}
+}
+
+impl<'a, Q, K, V, S> GetKeyValue<&'a Q> for IndexMap<K, V, S>
+where
+	Q: Hash + Equivalent<K> + ?Sized,
+	S: BuildHasher,
+{
+	#[inline(always)]
+	fn get_key_value(&self, key: &'a Q) -> Option<(&K, &V)> {
+		self.get_key_value(key)
+	}
+}
+
+impl<K, V, S> GetIndex for IndexMap<K, V, S> {
+	#[inline(always)]
+	fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+		self.get_index(index)
+	}
+}
+
+impl<K, V, S> GetIndexMut for IndexMap<K, V, S> {
+	#[inline(always)]
+	fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut V)> {
+		self.get_index_mut(index)
+	}
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> MapInsert<K> for IndexMap<K, V, S> {
+	type Output = Option<V>;
+
+	#[inline(always)]
+	fn insert(&mut self, key: K, value: V) -> Option<V> {
+		self.insert(key, value)
+	}
+}
+
+/// Removes the given key using [`IndexMap::swap_remove`].
+impl<'a, Q, K, V, S> Remove<&'a Q> for IndexMap<K, V, S>
+where
+	Q: Hash + Equivalent<K> + ?Sized,
+	S: BuildHasher,
+{
+	#[inline(always)]
+	fn remove(&mut self, key: &'a Q) -> Option<V> {
+		self.swap_remove(key)
+	}
+}
+
+impl<'a, Q, K, V, S> Remove<Swapping<&'a Q>> for IndexMap<K, V, S>
+where
+	Q: Hash + Equivalent<K> + ?Sized,
+	S: BuildHasher,
+{
+	#[inline(always)]
+	fn remove(&mut self, Swapping(key): Swapping<&'a Q>) -> Option<V> {
+		self.swap_remove(key)
+	}
+}
+
+impl<'a, Q, K, V, S> Remove<Shifting<&'a Q>> for IndexMap<K, V, S>
+where
+	Q: Hash + Equivalent<K> + ?Sized,
+	S: BuildHasher,
+{
+	#[inline(always)]
+	fn remove(&mut self, Shifting(key): Shifting<&'a Q>) -> Option<V> {
+		self.shift_remove(key)
+	}
+}
+
+impl<'a, Q, K, V, S> RemoveEntry<&'a Q> for IndexMap<K, V, S>
+where
+	Q: Hash + Equivalent<K> + ?Sized,
+	S: BuildHasher,
+{
+	#[inline(always)]
+	fn remove_entry(&mut self, key: &'a Q) -> Option<(K, V)> {
+		self.swap_remove_entry(key)
+	}
+}
+
+impl<'a, Q, K, V, S> RemoveEntry<Swapping<&'a Q>> for IndexMap<K, V, S>
+where
+	Q: Hash + Equivalent<K> + ?Sized,
+	S: BuildHasher,
+{
+	#[inline(always)]
+	fn remove_entry(&mut self, Swapping(key): Swapping<&'a Q>) -> Option<(K, V)> {
+		self.swap_remove_entry(key)
+	}
+}
+
+impl<'a, Q, K, V, S> RemoveEntry<Shifting<&'a Q>> for IndexMap<K, V, S>
+where
+	Q: Hash + Equivalent<K> + ?Sized,
+	S: BuildHasher,
+{
+	#[inline(always)]
+	fn remove_entry(&mut self, Shifting(key): Shifting<&'a Q>) -> Option<(K, V)> {
+		self.shift_remove_entry(key)
+	}
+}
+
+impl<K, V, S> Clear for IndexMap<K, V, S> {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.clear()
+	}
+}
+
+impl<K, V, S> Iter for IndexMap<K, V, S> {
+	type Iter<'a> = map::Values<'a, K, V> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.values()
+	}
+}
+
+impl<K, V, S> MapIter for IndexMap<K, V, S> {
+	type Iter<'a> = map::Iter<'a, K, V> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.iter()
+	}
+}
+
+impl<K, V, S> MapIterMut for IndexMap<K, V, S> {
+	type IterMut<'a> = map::IterMut<'a, K, V> where Self: 'a;
+
+	#[inline(always)]
+	fn iter_mut(&mut self) -> Self::IterMut<'_> {
+		self.iter_mut()
+	}
+}
+
+impl<K, V, S> Drain for IndexMap<K, V, S> {
+	type Drain<'a> = core::iter::Map<map::Drain<'a, K, V>, fn((K, V)) -> V> where Self: 'a;
+
+	#[inline(always)]
+	fn drain(&mut self) -> Self::Drain<'_> {
+		self.drain(..).map(|(_, v)| v)
+	}
+}
+
+impl<K, V, S> MapDrain for IndexMap<K, V, S> {
+	type Drain<'a> = map::Drain<'a, K, V> where Self: 'a;
+
+	#[inline(always)]
+	fn drain(&mut self) -> Self::Drain<'_> {
+		self.drain(..)
+	}
+}
+
+impl<K, V, S> Retain for IndexMap<K, V, S> {
+	#[inline(always)]
+	fn retain<F>(&mut self, mut f: F)
+	where
+		F: FnMut(&V) -> bool,
+	{
+		self.retain(|_, v| f(v))
+	}
+}
+
+impl<K, V, S> MapRetain for IndexMap<K, V, S> {
+	#[inline(always)]
+	fn retain<F>(&mut self, f: F)
+	where
+		F: FnMut(&K, &mut V) -> bool,
+	{
+		self.retain(f)
+	}
+}
+
+impl<K, V, S> IntoItems for IndexMap<K, V, S> {
+	type IntoItems = map::IntoValues<K, V>;
+
+	#[inline(always)]
+	fn into_items(self) -> Self::IntoItems {
+		self.into_values()
+	}
+}
+
+impl<K, V, S> MapIntoItems for IndexMap<K, V, S> {
+	type IntoItems = map::IntoIter<K, V>;
+
+	#[inline(always)]
+	fn into_items(self) -> Self::IntoItems {
+		self.into_iter()
+	}
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> ExtendMap<K> for IndexMap<K, V, S> {
+	#[inline(always)]
+	fn extend_map<I>(&mut self, items: I)
+	where
+		I: IntoIterator<Item = (K, V)>,
+	{
+		self.extend(items)
+	}
+}
+
+impl<K, V, S> TryReserve for IndexMap<K, V, S> {
+	type Error = TryReserveError;
+
+	#[inline(always)]
+	fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+		self.try_reserve(additional)
+	}
+}
+
+impl<T, S> Collection for IndexSet<T, S> {
+	type Item = T;
+}
+
+impl<T, S> CollectionRef for IndexSet<T, S> {
+	type ItemRef<'a> = &'a T where Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<T, S> CollectionMut for IndexSet<T, S> {
+	type ItemMut<'a> = &'a mut T where Self: 'a;
+
+	crate::covariant_item_mut!();
+}
+
+impl<T, S> SimpleCollectionRef for IndexSet<T, S> {
+	crate::simple_collection_ref!();
+}
+
+impl<T, S> SimpleCollectionMut for IndexSet<T, S> {
+	crate::simple_collection_mut!();
+}
+
+impl<T, S: Default> WithCapacity for IndexSet<T, S> {
+	#[inline(always)]
+	fn with_capacity(capacity: usize) -> Self {
+		IndexSet::with_capacity_and_hasher(capacity, S::default())
+	}
+}
+
+impl<T, S> Len for IndexSet<T, S> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.is_empty()
+	}
+}
+
+impl<T, S> Capacity for IndexSet<T, S> {
+	#[inline(always)]
+	fn capacity(&self) -> usize {
+		self.capacity()
+	}
+}
+
+impl<T, S> Reserve for IndexSet<T, S> {
+	#[inline(always)]
+	fn reserve(&mut self, additional: usize) {
+		self.reserve(additional)
+	}
+}
+
+impl<'a, Q, T, S> Get<&'a Q> for IndexSet<T, S>
+where
+	Q: Hash + Equivalent<T> + ?Sized,
+	S: BuildHasher,
+{
+	#[inline(always)]
+	fn get(&self, value: &'a Q) -> Option<&T> {
+		self.get(value)
+	}
+}
+
+impl<T, S> Get<usize> for IndexSet<T, S> {
+	#[inline(always)]
+	fn get(&self, index: usize) -> Option<&T> {
+		self.get_index(index)
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> Insert for IndexSet<T, S> {
+	type Output = bool;
+
+	#[inline(always)]
+	fn insert(&mut self, t: T) -> bool {
+		self.insert(t)
+	}
+}
+
+/// Removes the given value using [`IndexSet::swap_take`].
+impl<'a, Q, T, S> Remove<&'a Q> for IndexSet<T, S>
+where
+	Q: Hash + Equivalent<T> + ?Sized,
+	S: BuildHasher,
+{
+	#[inline(always)]
+	fn remove(&mut self, value: &'a Q) -> Option<T> {
+		self.swap_take(value)
+	}
+}
+
+impl<'a, Q, T, S> Remove<Swapping<&'a Q>> for IndexSet<T, S>
+where
+	Q: Hash + Equivalent<T> + ?Sized,
+	S: BuildHasher,
+{
+	#[inline(always)]
+	fn remove(&mut self, Swapping(value): Swapping<&'a Q>) -> Option<T> {
+		self.swap_take(value)
+	}
+}
+
+impl<'a, Q, T, S> Remove<Shifting<&'a Q>> for IndexSet<T, S>
+where
+	Q: Hash + Equivalent<T> + ?Sized,
+	S: BuildHasher,
+{
+	#[inline(always)]
+	fn remove(&mut self, Shifting(value): Shifting<&'a Q>) -> Option<T> {
+		self.shift_take(value)
+	}
+}
+
+impl<T, S> Clear for IndexSet<T, S> {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.clear()
+	}
+}
+
+impl<T, S> Iter for IndexSet<T, S> {
+	type Iter<'a> = set::Iter<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.iter()
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> Union for IndexSet<T, S> {
+	type Union<'a> = set::Union<'a, T, S> where Self: 'a;
+
+	#[inline(always)]
+	fn union<'a>(&'a self, other: &'a Self) -> Self::Union<'a> {
+		self.union(other)
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> UnionWith for IndexSet<T, S> {
+	#[inline(always)]
+	fn union_with(&mut self, other: Self) {
+		self.extend(other)
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> Intersection for IndexSet<T, S> {
+	type Intersection<'a> = set::Intersection<'a, T, S> where Self: 'a;
+
+	#[inline(always)]
+	fn intersection<'a>(&'a self, other: &'a Self) -> Self::Intersection<'a> {
+		self.intersection(other)
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> RetainIn for IndexSet<T, S> {
+	#[inline(always)]
+	fn retain_in(&mut self, other: &Self) {
+		self.retain(|t| other.contains(t))
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> Difference for IndexSet<T, S> {
+	type Difference<'a> = set::Difference<'a, T, S> where Self: 'a;
+
+	#[inline(always)]
+	fn difference<'a>(&'a self, other: &'a Self) -> Self::Difference<'a> {
+		self.difference(other)
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> RemoveIn for IndexSet<T, S> {
+	#[inline(always)]
+	fn remove_in(&mut self, other: &Self) {
+		self.retain(|t| !other.contains(t))
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> SymmetricDifference for IndexSet<T, S> {
+	type SymmetricDifference<'a> = set::SymmetricDifference<'a, T, S, S> where Self: 'a;
+
+	#[inline(always)]
+	fn symmetric_difference<'a>(&'a self, other: &'a Self) -> Self::SymmetricDifference<'a> {
+		self.symmetric_difference(other)
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> SymmetricDifferenceWith for IndexSet<T, S> {
+	fn symmetric_difference_with(&mut self, other: Self) {
+		for t in other {
+			if !self.shift_remove(&t) {
+				self.insert(t);
+			}
+		}
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> IsSubset for IndexSet<T, S> {
+	#[inline(always)]
+	fn is_subset(&self, other: &Self) -> bool {
+		self.is_subset(other)
+	}
+
+	#[inline(always)]
+	fn is_superset(&self, other: &Self) -> bool {
+		self.is_superset(other)
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> IsDisjoint for IndexSet<T, S> {
+	#[inline(always)]
+	fn is_disjoint(&self, other: &Self) -> bool {
+		self.is_disjoint(other)
+	}
+}
+
+impl<T, S> Drain for IndexSet<T, S> {
+	type Drain<'a> = set::Drain<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn drain(&mut self) -> Self::Drain<'_> {
+		self.drain(..)
+	}
+}
+
+impl<T, S> Retain for IndexSet<T, S> {
+	#[inline(always)]
+	fn retain<F>(&mut self, f: F)
+	where
+		F: FnMut(&T) -> bool,
+	{
+		self.retain(f)
+	}
+}
+
+impl<T, S> IntoItems for IndexSet<T, S> {
+	type IntoItems = set::IntoIter<T>;
+
+	#[inline(always)]
+	fn into_items(self) -> Self::IntoItems {
+		self.into_iter()
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> ExtendItems for IndexSet<T, S> {
+	#[inline(always)]
+	fn extend_items<I>(&mut self, items: I)
+	where
+		I: IntoIterator<Item = T>,
+	{
+		self.extend(items)
+	}
+}
+
+impl<T, S> TryReserve for IndexSet<T, S> {
+	type Error = TryReserveError;
+
+	#[inline(always)]
+	fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+		self.try_reserve(additional)
+	}
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> Append for IndexMap<K, V, S> {
+	#[inline(always)]
+	fn append(&mut self, other: &mut Self) {
+		self.extend(other.drain(..))
+	}
+}
+
+impl<K, V, S: Clone> SplitOff for IndexMap<K, V, S> {
+	#[inline(always)]
+	fn split_off(&mut self, at: usize) -> Self {
+		self.split_off(at)
+	}
+}
+
+impl<K, V, S> Truncate for IndexMap<K, V, S> {
+	#[inline(always)]
+	fn truncate(&mut self, len: usize) {
+		self.truncate(len)
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> Append for IndexSet<T, S> {
+	#[inline(always)]
+	fn append(&mut self, other: &mut Self) {
+		self.extend(other.drain(..))
+	}
+}
+
+impl<T, S: Clone> SplitOff for IndexSet<T, S> {
+	#[inline(always)]
+	fn split_off(&mut self, at: usize) -> Self {
+		self.split_off(at)
+	}
+}
+
+impl<T, S> Truncate for IndexSet<T, S> {
+	#[inline(always)]
+	fn truncate(&mut self, len: usize) {
+		self.truncate(len)
+	}
+}
+
+impl<K, V, S> Keys for IndexMap<K, V, S> {
+	type Keys<'a> = map::Keys<'a, K, V> where Self: 'a;
+
+	#[inline(always)]
+	fn keys(&self) -> Self::Keys<'_> {
+		self.keys()
+	}
+}
+
+impl<K, V, S> Values for IndexMap<K, V, S> {
+	type Values<'a> = map::Values<'a, K, V> where Self: 'a;
+
+	#[inline(always)]
+	fn values(&self) -> Self::Values<'_> {
+		self.values()
+	}
+}
+
+impl<K, V, S> ValuesMut for IndexMap<K, V, S> {
+	type ValuesMut<'a> = map::ValuesMut<'a, K, V> where Self: 'a;
+
+	#[inline(always)]
+	fn values_mut(&mut self) -> Self::ValuesMut<'_> {
+		self.values_mut()
+	}
+}
+
+impl<K, V, S> IntoKeys for IndexMap<K, V, S> {
+	type IntoKeys = map::IntoKeys<K, V>;
+
+	#[inline(always)]
+	fn into_keys(self) -> Self::IntoKeys {
+		self.into_keys()
+	}
+}
+
+impl<K, V, S> IntoValues for IndexMap<K, V, S> {
+	type IntoValues = map::IntoValues<K, V>;
+
+	#[inline(always)]
+	fn into_values(self) -> Self::IntoValues {
+		self.into_values()
+	}
+}
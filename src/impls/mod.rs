@@ -1,3 +1,8 @@
+#[macro_use]
+mod forward;
+
+mod core;
+
 #[cfg(feature = "alloc")]
 mod alloc;
 
@@ -15,3 +20,21 @@ mod serde_json;
 
 #[cfg(feature = "ijson")]
 mod ijson;
+
+#[cfg(feature = "arrayvec")]
+mod arrayvec;
+
+#[cfg(feature = "heapless")]
+mod heapless;
+
+#[cfg(feature = "indexmap")]
+mod indexmap;
+
+#[cfg(feature = "hashbrown")]
+mod hashbrown;
+
+#[cfg(feature = "dashmap")]
+mod dashmap;
+
+#[cfg(feature = "crossbeam-queue")]
+mod crossbeam_queue;
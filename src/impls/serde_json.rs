@@ -1,7 +1,9 @@
 use crate::{
-	Clear, Collection, CollectionMut, CollectionRef, Get, GetKeyValue, GetMut, Keyed, KeyedRef,
-	Len, MapInsert, MapIter, MapIterMut, Remove, SimpleCollectionMut, SimpleCollectionRef,
-	SimpleKeyedRef,
+	Append, Clear, Collection, CollectionMut, CollectionRef, Drain, Entry, EntryApi, ExtendMap, Get,
+	GetKeyValue, GetMut, IntoItems, IntoKeys, IntoValues, Keyed, KeyedRef, Keys, Len, MapDrain,
+	MapInsert, MapIntoItems, MapIter, MapIterMut, MapRetain, OccupiedEntry, Remove, RemoveEntry,
+	Retain, SimpleCollectionMut, SimpleCollectionRef, SimpleKeyedRef, VacantEntry, Values,
+	ValuesMut, WithCapacity,
 };
 use std::{borrow::Borrow, cmp::Ord, hash::Hash};
 
@@ -48,6 +50,13 @@ impl SimpleKeyedRef for serde_json::Map<String, serde_json::Value> {
 	crate::simple_keyed_ref!();
 }
 
+impl WithCapacity for serde_json::Map<String, serde_json::Value> {
+	#[inline(always)]
+	fn with_capacity(capacity: usize) -> Self {
+		Self::with_capacity(capacity)
+	}
+}
+
 impl Len for serde_json::Map<String, serde_json::Value> {
 	#[inline(always)]
 	fn len(&self) -> usize {
@@ -132,9 +141,213 @@ where
 	}
 }
 
+impl<'a, Q: ?Sized> RemoveEntry<&'a Q> for serde_json::Map<String, serde_json::Value>
+where
+	String: Borrow<Q>,
+	Q: Ord + Hash,
+{
+	#[inline(always)]
+	fn remove_entry(&mut self, key: &'a Q) -> Option<(String, serde_json::Value)> {
+		self.remove_entry(key)
+	}
+}
+
 impl Clear for serde_json::Map<String, serde_json::Value> {
 	#[inline(always)]
 	fn clear(&mut self) {
 		self.clear()
 	}
 }
+
+impl<K: Into<String>> EntryApi<K> for serde_json::Map<String, serde_json::Value> {
+	type Occupied<'a> = serde_json::map::OccupiedEntry<'a> where Self: 'a;
+	type Vacant<'a> = serde_json::map::VacantEntry<'a> where Self: 'a;
+
+	#[inline(always)]
+	fn entry(&mut self, key: K) -> Entry<Self::Occupied<'_>, Self::Vacant<'_>> {
+		match self.entry(key) {
+			serde_json::map::Entry::Occupied(o) => Entry::Occupied(o),
+			serde_json::map::Entry::Vacant(v) => Entry::Vacant(v),
+		}
+	}
+}
+
+impl<'a> OccupiedEntry<'a> for serde_json::map::OccupiedEntry<'a> {
+	type Key = String;
+	type Item = serde_json::Value;
+
+	#[inline(always)]
+	fn key(&self) -> &String {
+		self.key()
+	}
+
+	#[inline(always)]
+	fn get(&self) -> &serde_json::Value {
+		self.get()
+	}
+
+	#[inline(always)]
+	fn get_mut(&mut self) -> &mut serde_json::Value {
+		self.get_mut()
+	}
+
+	#[inline(always)]
+	fn into_mut(self) -> &'a mut serde_json::Value {
+		self.into_mut()
+	}
+
+	#[inline(always)]
+	fn insert(&mut self, value: serde_json::Value) -> serde_json::Value {
+		self.insert(value)
+	}
+
+	#[inline(always)]
+	fn remove_entry(self) -> (String, serde_json::Value) {
+		self.remove_entry()
+	}
+
+	#[inline(always)]
+	fn remove(self) -> serde_json::Value {
+		self.remove()
+	}
+}
+
+impl<'a> VacantEntry<'a> for serde_json::map::VacantEntry<'a> {
+	type Key = String;
+	type Item = serde_json::Value;
+
+	#[inline(always)]
+	fn key(&self) -> &String {
+		self.key()
+	}
+
+	#[inline(always)]
+	fn insert(self, value: serde_json::Value) -> &'a mut serde_json::Value {
+		self.insert(value)
+	}
+}
+
+impl Drain for serde_json::Map<String, serde_json::Value> {
+	type Drain<'a> = core::iter::Map<
+		serde_json::map::IntoIter,
+		fn((String, serde_json::Value)) -> serde_json::Value,
+	>;
+
+	#[inline(always)]
+	fn drain(&mut self) -> Self::Drain<'_> {
+		core::mem::take(self).into_iter().map(|(_, v)| v)
+	}
+}
+
+impl MapDrain for serde_json::Map<String, serde_json::Value> {
+	type Drain<'a> = serde_json::map::IntoIter;
+
+	#[inline(always)]
+	fn drain(&mut self) -> Self::Drain<'_> {
+		core::mem::take(self).into_iter()
+	}
+}
+
+impl Retain for serde_json::Map<String, serde_json::Value> {
+	#[inline(always)]
+	fn retain<F>(&mut self, mut f: F)
+	where
+		F: FnMut(&serde_json::Value) -> bool,
+	{
+		self.retain(|_, v| f(v))
+	}
+}
+
+impl MapRetain for serde_json::Map<String, serde_json::Value> {
+	#[inline(always)]
+	fn retain<F>(&mut self, f: F)
+	where
+		F: FnMut(&String, &mut serde_json::Value) -> bool,
+	{
+		self.retain(f)
+	}
+}
+
+impl IntoItems for serde_json::Map<String, serde_json::Value> {
+	type IntoItems = core::iter::Map<
+		serde_json::map::IntoIter,
+		fn((String, serde_json::Value)) -> serde_json::Value,
+	>;
+
+	#[inline(always)]
+	fn into_items(self) -> Self::IntoItems {
+		self.into_iter().map(|(_, v)| v)
+	}
+}
+
+impl MapIntoItems for serde_json::Map<String, serde_json::Value> {
+	type IntoItems = serde_json::map::IntoIter;
+
+	#[inline(always)]
+	fn into_items(self) -> Self::IntoItems {
+		self.into_iter()
+	}
+}
+
+impl ExtendMap<String> for serde_json::Map<String, serde_json::Value> {
+	#[inline(always)]
+	fn extend_map<I>(&mut self, items: I)
+	where
+		I: IntoIterator<Item = (String, serde_json::Value)>,
+	{
+		self.extend(items)
+	}
+}
+
+impl Append for serde_json::Map<String, serde_json::Value> {
+	#[inline(always)]
+	fn append(&mut self, other: &mut Self) {
+		self.extend(core::mem::take(other))
+	}
+}
+
+impl Keys for serde_json::Map<String, serde_json::Value> {
+	type Keys<'a> = serde_json::map::Keys<'a> where Self: 'a;
+
+	#[inline(always)]
+	fn keys(&self) -> Self::Keys<'_> {
+		self.keys()
+	}
+}
+
+impl Values for serde_json::Map<String, serde_json::Value> {
+	type Values<'a> = serde_json::map::Values<'a> where Self: 'a;
+
+	#[inline(always)]
+	fn values(&self) -> Self::Values<'_> {
+		self.values()
+	}
+}
+
+impl ValuesMut for serde_json::Map<String, serde_json::Value> {
+	type ValuesMut<'a> = serde_json::map::ValuesMut<'a> where Self: 'a;
+
+	#[inline(always)]
+	fn values_mut(&mut self) -> Self::ValuesMut<'_> {
+		self.values_mut()
+	}
+}
+
+impl IntoKeys for serde_json::Map<String, serde_json::Value> {
+	type IntoKeys =
+		core::iter::Map<serde_json::map::IntoIter, fn((String, serde_json::Value)) -> String>;
+
+	#[inline(always)]
+	fn into_keys(self) -> Self::IntoKeys {
+		self.into_iter().map(|(k, _)| k)
+	}
+}
+
+impl IntoValues for serde_json::Map<String, serde_json::Value> {
+	type IntoValues = serde_json::map::IntoValues;
+
+	#[inline(always)]
+	fn into_values(self) -> Self::IntoValues {
+		self.into_values()
+	}
+}
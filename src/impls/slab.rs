@@ -1,6 +1,7 @@
 use crate::{
-	Capacity, Clear, Collection, CollectionMut, CollectionRef, Get, GetMut, Insert, Len, Remove,
-	Reserve, SimpleCollectionMut, SimpleCollectionRef, WithCapacity,
+	Capacity, Clear, Collection, CollectionMut, CollectionRef, Drain, ExtendItems, Get, GetMut,
+	Insert, IntoItems, Len, Remove, Reserve, Retain, SimpleCollectionMut, SimpleCollectionRef,
+	WithCapacity,
 };
 use slab::Slab;
 
@@ -87,3 +88,45 @@ impl<T> Clear for Slab<T> {
 		self.clear()
 	}
 }
+
+impl<T> Drain for Slab<T> {
+	type Drain<'a> = slab::Drain<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn drain(&mut self) -> Self::Drain<'_> {
+		self.drain()
+	}
+}
+
+impl<T> Retain for Slab<T> {
+	#[inline(always)]
+	fn retain<F>(&mut self, mut f: F)
+	where
+		F: FnMut(&T) -> bool,
+	{
+		self.retain(|_, t| f(t))
+	}
+}
+
+impl<T> IntoItems for Slab<T> {
+	type IntoItems = core::iter::Map<slab::IntoIter<T>, fn((usize, T)) -> T>;
+
+	#[inline(always)]
+	fn into_items(self) -> Self::IntoItems {
+		self.into_iter().map(|(_, t)| t)
+	}
+}
+
+impl<T> ExtendItems for Slab<T> {
+	#[inline(always)]
+	fn extend_items<I>(&mut self, items: I)
+	where
+		I: IntoIterator<Item = T>,
+	{
+		let items = items.into_iter();
+		self.reserve(items.size_hint().0);
+		for t in items {
+			self.insert(t);
+		}
+	}
+}
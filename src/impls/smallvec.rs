@@ -1,8 +1,12 @@
 use crate::{
-	Capacity, Clear, Collection, CollectionMut, CollectionRef, Get, GetMut, Iter, IterMut, Len,
-	PopBack, PushBack, Remove, Reserve, SimpleCollectionMut, SimpleCollectionRef, WithCapacity,
+	Append, Capacity, Clear, Collection, CollectionMut, CollectionRef, Contiguous, ContiguousMut,
+	Drain, DrainRange, ExtendItems, Get, GetMut, InsertAt, IntoItems, Iter, IterMut, Len, PopBack,
+	PushBack, Remove, Reserve, Resize, ResizeWith, Retain, SimpleCollectionMut,
+	SimpleCollectionRef, SplitOff, SwapRemove, Truncate, TryPushBack, TryPushError, TryReserve,
+	TryWithCapacity, WithCapacity,
 };
-use smallvec::{Array, SmallVec};
+use core::ops::RangeBounds;
+use smallvec::{Array, CollectionAllocErr, SmallVec};
 
 impl<A: Array> Collection for SmallVec<A> {
 	type Item = A::Item;
@@ -126,3 +130,161 @@ impl<A: Array> IterMut for SmallVec<A> {
 		self.as_mut_slice().iter_mut()
 	}
 }
+
+impl<A: Array> Drain for SmallVec<A> {
+	type Drain<'a> = smallvec::Drain<'a, A> where Self: 'a;
+
+	#[inline(always)]
+	fn drain(&mut self) -> Self::Drain<'_> {
+		self.drain(..)
+	}
+}
+
+impl<A: Array, R: RangeBounds<usize>> DrainRange<R> for SmallVec<A> {
+	type DrainRange<'a> = smallvec::Drain<'a, A> where Self: 'a;
+
+	#[inline(always)]
+	fn drain_range(&mut self, range: R) -> Self::DrainRange<'_> {
+		self.drain(range)
+	}
+}
+
+impl<A: Array> Retain for SmallVec<A> {
+	#[inline(always)]
+	fn retain<F>(&mut self, mut f: F)
+	where
+		F: FnMut(&A::Item) -> bool,
+	{
+		self.retain(|t| f(t))
+	}
+}
+
+impl<A: Array> IntoItems for SmallVec<A> {
+	type IntoItems = smallvec::IntoIter<A>;
+
+	#[inline(always)]
+	fn into_items(self) -> Self::IntoItems {
+		self.into_iter()
+	}
+}
+
+impl<A: Array> ExtendItems for SmallVec<A> {
+	#[inline(always)]
+	fn extend_items<I>(&mut self, items: I)
+	where
+		I: IntoIterator<Item = A::Item>,
+	{
+		self.extend(items)
+	}
+}
+
+impl<A: Array> TryReserve for SmallVec<A> {
+	type Error = CollectionAllocErr;
+
+	#[inline(always)]
+	fn try_reserve(&mut self, additional: usize) -> Result<(), CollectionAllocErr> {
+		self.try_reserve(additional)
+	}
+}
+
+impl<A: Array> TryWithCapacity for SmallVec<A> {
+	type Error = CollectionAllocErr;
+
+	#[inline(always)]
+	fn try_with_capacity(capacity: usize) -> Result<Self, CollectionAllocErr> {
+		let mut result = Self::new();
+		result.try_reserve(capacity)?;
+		Ok(result)
+	}
+}
+
+impl<A: Array> TryPushBack for SmallVec<A> {
+	type Error = TryPushError<A::Item, CollectionAllocErr>;
+
+	#[inline(always)]
+	fn try_push_back(&mut self, element: A::Item) -> Result<(), Self::Error> {
+		match self.try_reserve(1) {
+			Ok(()) => {
+				self.push(element);
+				Ok(())
+			}
+			Err(e) => Err(TryPushError::new(element, e)),
+		}
+	}
+}
+
+impl<A: Array> InsertAt for SmallVec<A> {
+	#[inline(always)]
+	fn insert_at(&mut self, index: usize, element: A::Item) {
+		self.insert(index, element)
+	}
+}
+
+impl<A: Array> SwapRemove for SmallVec<A> {
+	#[inline(always)]
+	fn swap_remove(&mut self, index: usize) -> Option<A::Item> {
+		if index < self.len() {
+			Some(self.swap_remove(index))
+		} else {
+			None
+		}
+	}
+}
+
+impl<A: Array> Truncate for SmallVec<A> {
+	#[inline(always)]
+	fn truncate(&mut self, len: usize) {
+		self.truncate(len)
+	}
+}
+
+impl<A: Array> Resize for SmallVec<A>
+where
+	A::Item: Clone,
+{
+	#[inline(always)]
+	fn resize(&mut self, new_len: usize, value: A::Item) {
+		self.resize(new_len, value)
+	}
+}
+
+impl<A: Array> ResizeWith for SmallVec<A> {
+	#[inline(always)]
+	fn resize_with<F>(&mut self, new_len: usize, f: F)
+	where
+		F: FnMut() -> A::Item,
+	{
+		match new_len.checked_sub(self.len()) {
+			Some(additional) => self.extend(core::iter::repeat_with(f).take(additional)),
+			None => self.truncate(new_len),
+		}
+	}
+}
+
+impl<A: Array> SplitOff for SmallVec<A> {
+	#[inline(always)]
+	fn split_off(&mut self, at: usize) -> Self {
+		self.drain(at..).collect()
+	}
+}
+
+impl<A: Array> Append for SmallVec<A> {
+	#[inline(always)]
+	fn append(&mut self, other: &mut Self) {
+		self.append(other)
+	}
+}
+
+impl<A: Array> Contiguous for SmallVec<A> {
+	#[inline(always)]
+	fn as_slice(&self) -> &[A::Item] {
+		self.as_slice()
+	}
+}
+
+impl<A: Array> ContiguousMut for SmallVec<A> {
+	#[inline(always)]
+	fn as_mut_slice(&mut self) -> &mut [A::Item] {
+		self.as_mut_slice()
+	}
+}
@@ -1,49 +1,62 @@
 use crate::{
-	Clear, Collection, CollectionMut, CollectionRef, Get, GetKeyValue, GetMut, Iter, Keyed,
-	KeyedRef, Len, MapInsert, MapIter, MapIterMut, Remove, SimpleCollectionMut,
-	SimpleCollectionRef, SimpleKeyedRef,
+	Append, Clear, Collection, CollectionMut, CollectionRef, Drain, Entry, EntryApi, ExtendMap, Get,
+	GetKeyValue, GetMut, IntoItems, IntoKeys, IntoValues, Iter, Keyed, KeyedRef, Keys, Len,
+	MapDrain, MapInsert, MapIntoItems, MapIter, MapIterMut, MapRetain, MapTryInsert, OccupiedEntry,
+	Remove, RemoveEntry, Retain, SimpleCollectionMut, SimpleCollectionRef, SimpleKeyedRef,
+	TryReserve, TryWithCapacity, VacantEntry, Values, ValuesMut, WithCapacity,
+};
+use std::{
+	borrow::Borrow,
+	collections::{hash_map, HashMap, TryReserveError},
+	hash::{BuildHasher, Hash},
 };
-use std::{borrow::Borrow, collections::HashMap, hash::Hash};
 
-impl<K, V> Collection for HashMap<K, V> {
+impl<K, V, S> Collection for HashMap<K, V, S> {
 	type Item = V;
 }
 
-impl<K, V> CollectionRef for HashMap<K, V> {
+impl<K, V, S> CollectionRef for HashMap<K, V, S> {
 	type ItemRef<'a> = &'a V where Self: 'a;
 
 	crate::covariant_item_ref!();
 }
 
-impl<K, V> CollectionMut for HashMap<K, V> {
+impl<K, V, S> CollectionMut for HashMap<K, V, S> {
 	type ItemMut<'a> = &'a mut V where Self: 'a;
 
 	crate::covariant_item_mut!();
 }
 
-impl<K, V> SimpleCollectionRef for HashMap<K, V> {
+impl<K, V, S> SimpleCollectionRef for HashMap<K, V, S> {
 	crate::simple_collection_ref!();
 }
 
-impl<K, V> SimpleCollectionMut for HashMap<K, V> {
+impl<K, V, S> SimpleCollectionMut for HashMap<K, V, S> {
 	crate::simple_collection_mut!();
 }
 
-impl<K, V> Keyed for HashMap<K, V> {
+impl<K, V, S> Keyed for HashMap<K, V, S> {
 	type Key = K;
 }
 
-impl<K, V> KeyedRef for HashMap<K, V> {
+impl<K, V, S> KeyedRef for HashMap<K, V, S> {
 	type KeyRef<'a> = &'a K where Self: 'a;
 
 	crate::covariant_key_ref!();
 }
 
-impl<K, V> SimpleKeyedRef for HashMap<K, V> {
+impl<K, V, S> SimpleKeyedRef for HashMap<K, V, S> {
 	crate::simple_keyed_ref!();
 }
 
-impl<K, V> Len for HashMap<K, V> {
+impl<K, V, S: Default> WithCapacity for HashMap<K, V, S> {
+	#[inline(always)]
+	fn with_capacity(capacity: usize) -> Self {
+		HashMap::with_capacity_and_hasher(capacity, S::default())
+	}
+}
+
+impl<K, V, S> Len for HashMap<K, V, S> {
 	#[inline(always)]
 	fn len(&self) -> usize {
 		self.len()
@@ -55,7 +68,7 @@ impl<K, V> Len for HashMap<K, V> {
 	}
 }
 
-impl<'a, Q, K: Hash + Eq, V> Get<&'a Q> for HashMap<K, V>
+impl<'a, Q, K: Hash + Eq, V, S: BuildHasher> Get<&'a Q> for HashMap<K, V, S>
 where
 	K: Borrow<Q>,
 	Q: Hash + Eq + ?Sized,
@@ -66,7 +79,7 @@ where
 	}
 }
 
-impl<'a, Q, K: Hash + Eq, V> GetMut<&'a Q> for HashMap<K, V>
+impl<'a, Q, K: Hash + Eq, V, S: BuildHasher> GetMut<&'a Q> for HashMap<K, V, S>
 where
 	K: Borrow<Q>,
 	Q: Hash + Eq + ?Sized,
@@ -77,7 +90,7 @@ where
 	}
 }
 
-impl<'a, Q, K: Hash + Eq, V> GetKeyValue<&'a Q> for HashMap<K, V>
+impl<'a, Q, K: Hash + Eq, V, S: BuildHasher> GetKeyValue<&'a Q> for HashMap<K, V, S>
 where
 	K: Borrow<Q>,
 	Q: Hash + Eq + ?Sized,
@@ -88,7 +101,7 @@ where
 	}
 }
 
-impl<K: Hash + Eq, V> MapInsert<K> for HashMap<K, V> {
+impl<K: Hash + Eq, V, S: BuildHasher> MapInsert<K> for HashMap<K, V, S> {
 	type Output = Option<V>;
 
 	#[inline(always)]
@@ -97,7 +110,7 @@ impl<K: Hash + Eq, V> MapInsert<K> for HashMap<K, V> {
 	}
 }
 
-impl<'a, Q, K: Hash + Eq, V> Remove<&'a Q> for HashMap<K, V>
+impl<'a, Q, K: Hash + Eq, V, S: BuildHasher> Remove<&'a Q> for HashMap<K, V, S>
 where
 	K: Borrow<Q>,
 	Q: Hash + Eq + ?Sized,
@@ -108,14 +121,25 @@ where
 	}
 }
 
-impl<K, V> Clear for HashMap<K, V> {
+impl<'a, Q, K: Hash + Eq, V, S: BuildHasher> RemoveEntry<&'a Q> for HashMap<K, V, S>
+where
+	K: Borrow<Q>,
+	Q: Hash + Eq + ?Sized,
+{
+	#[inline(always)]
+	fn remove_entry(&mut self, key: &'a Q) -> Option<(K, V)> {
+		self.remove_entry(key)
+	}
+}
+
+impl<K, V, S> Clear for HashMap<K, V, S> {
 	#[inline(always)]
 	fn clear(&mut self) {
 		self.clear()
 	}
 }
 
-impl<K, V> Iter for HashMap<K, V> {
+impl<K, V, S> Iter for HashMap<K, V, S> {
 	type Iter<'a> = std::collections::hash_map::Values<'a, K, V> where Self: 'a;
 
 	#[inline(always)]
@@ -124,7 +148,7 @@ impl<K, V> Iter for HashMap<K, V> {
 	}
 }
 
-impl<K, V> MapIter for HashMap<K, V> {
+impl<K, V, S> MapIter for HashMap<K, V, S> {
 	type Iter<'a> = std::collections::hash_map::Iter<'a, K, V> where Self: 'a;
 
 	#[inline(always)]
@@ -133,7 +157,7 @@ impl<K, V> MapIter for HashMap<K, V> {
 	}
 }
 
-impl<K, V> MapIterMut for HashMap<K, V> {
+impl<K, V, S> MapIterMut for HashMap<K, V, S> {
 	type IterMut<'a> = std::collections::hash_map::IterMut<'a, K, V> where Self: 'a;
 
 	#[inline(always)]
@@ -141,3 +165,220 @@ impl<K, V> MapIterMut for HashMap<K, V> {
 		self.iter_mut()
 	}
 }
+
+impl<K: Hash + Eq, V, S: BuildHasher> EntryApi<K> for HashMap<K, V, S> {
+	type Occupied<'a> = std::collections::hash_map::OccupiedEntry<'a, K, V> where Self: 'a;
+	type Vacant<'a> = std::collections::hash_map::VacantEntry<'a, K, V> where Self: 'a;
+
+	#[inline(always)]
+	fn entry(&mut self, key: K) -> Entry<Self::Occupied<'_>, Self::Vacant<'_>> {
+		match self.entry(key) {
+			std::collections::hash_map::Entry::Occupied(o) => Entry::Occupied(o),
+			std::collections::hash_map::Entry::Vacant(v) => Entry::Vacant(v),
+		}
+	}
+}
+
+impl<'a, K, V> OccupiedEntry<'a> for std::collections::hash_map::OccupiedEntry<'a, K, V> {
+	type Key = K;
+	type Item = V;
+
+	#[inline(always)]
+	fn key(&self) -> &K {
+		self.key()
+	}
+
+	#[inline(always)]
+	fn get(&self) -> &V {
+		self.get()
+	}
+
+	#[inline(always)]
+	fn get_mut(&mut self) -> &mut V {
+		self.get_mut()
+	}
+
+	#[inline(always)]
+	fn into_mut(self) -> &'a mut V {
+		self.into_mut()
+	}
+
+	#[inline(always)]
+	fn insert(&mut self, value: V) -> V {
+		self.insert(value)
+	}
+
+	#[inline(always)]
+	fn remove_entry(self) -> (K, V) {
+		self.remove_entry()
+	}
+
+	#[inline(always)]
+	fn remove(self) -> V {
+		self.remove()
+	}
+}
+
+impl<'a, K, V> VacantEntry<'a> for std::collections::hash_map::VacantEntry<'a, K, V> {
+	type Key = K;
+	type Item = V;
+
+	#[inline(always)]
+	fn key(&self) -> &K {
+		self.key()
+	}
+
+	#[inline(always)]
+	fn insert(self, value: V) -> &'a mut V {
+		self.insert(value)
+	}
+}
+
+impl<K, V, S> Drain for HashMap<K, V, S> {
+	type Drain<'a> = core::iter::Map<hash_map::Drain<'a, K, V>, fn((K, V)) -> V> where Self: 'a;
+
+	#[inline(always)]
+	fn drain(&mut self) -> Self::Drain<'_> {
+		self.drain().map(|(_, v)| v)
+	}
+}
+
+impl<K, V, S> MapDrain for HashMap<K, V, S> {
+	type Drain<'a> = hash_map::Drain<'a, K, V> where Self: 'a;
+
+	#[inline(always)]
+	fn drain(&mut self) -> Self::Drain<'_> {
+		self.drain()
+	}
+}
+
+impl<K, V, S> Retain for HashMap<K, V, S> {
+	#[inline(always)]
+	fn retain<F>(&mut self, mut f: F)
+	where
+		F: FnMut(&V) -> bool,
+	{
+		self.retain(|_, v| f(v))
+	}
+}
+
+impl<K, V, S> MapRetain for HashMap<K, V, S> {
+	#[inline(always)]
+	fn retain<F>(&mut self, f: F)
+	where
+		F: FnMut(&K, &mut V) -> bool,
+	{
+		self.retain(f)
+	}
+}
+
+impl<K, V, S> IntoItems for HashMap<K, V, S> {
+	type IntoItems = hash_map::IntoValues<K, V>;
+
+	#[inline(always)]
+	fn into_items(self) -> Self::IntoItems {
+		self.into_values()
+	}
+}
+
+impl<K, V, S> MapIntoItems for HashMap<K, V, S> {
+	type IntoItems = hash_map::IntoIter<K, V>;
+
+	#[inline(always)]
+	fn into_items(self) -> Self::IntoItems {
+		self.into_iter()
+	}
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> ExtendMap<K> for HashMap<K, V, S> {
+	#[inline(always)]
+	fn extend_map<I>(&mut self, items: I)
+	where
+		I: IntoIterator<Item = (K, V)>,
+	{
+		self.extend(items)
+	}
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> TryReserve for HashMap<K, V, S> {
+	type Error = TryReserveError;
+
+	#[inline(always)]
+	fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+		self.try_reserve(additional)
+	}
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher + Default> TryWithCapacity for HashMap<K, V, S> {
+	type Error = TryReserveError;
+
+	#[inline(always)]
+	fn try_with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+		let mut result = Self::with_hasher(S::default());
+		result.try_reserve(capacity)?;
+		Ok(result)
+	}
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> MapTryInsert<K> for HashMap<K, V, S> {
+	type Output = Option<V>;
+	type Error = TryReserveError;
+
+	#[inline(always)]
+	fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, TryReserveError> {
+		self.try_reserve(1)?;
+		Ok(self.insert(key, value))
+	}
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> Append for HashMap<K, V, S> {
+	#[inline(always)]
+	fn append(&mut self, other: &mut Self) {
+		self.extend(other.drain())
+	}
+}
+
+impl<K, V, S> Keys for HashMap<K, V, S> {
+	type Keys<'a> = hash_map::Keys<'a, K, V> where Self: 'a;
+
+	#[inline(always)]
+	fn keys(&self) -> Self::Keys<'_> {
+		self.keys()
+	}
+}
+
+impl<K, V, S> Values for HashMap<K, V, S> {
+	type Values<'a> = hash_map::Values<'a, K, V> where Self: 'a;
+
+	#[inline(always)]
+	fn values(&self) -> Self::Values<'_> {
+		self.values()
+	}
+}
+
+impl<K, V, S> ValuesMut for HashMap<K, V, S> {
+	type ValuesMut<'a> = hash_map::ValuesMut<'a, K, V> where Self: 'a;
+
+	#[inline(always)]
+	fn values_mut(&mut self) -> Self::ValuesMut<'_> {
+		self.values_mut()
+	}
+}
+
+impl<K, V, S> IntoKeys for HashMap<K, V, S> {
+	type IntoKeys = hash_map::IntoKeys<K, V>;
+
+	#[inline(always)]
+	fn into_keys(self) -> Self::IntoKeys {
+		self.into_keys()
+	}
+}
+
+impl<K, V, S> IntoValues for HashMap<K, V, S> {
+	type IntoValues = hash_map::IntoValues<K, V>;
+
+	#[inline(always)]
+	fn into_values(self) -> Self::IntoValues {
+		self.into_values()
+	}
+}
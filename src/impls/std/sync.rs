@@ -0,0 +1,160 @@
+use crate::{
+	concurrent::{
+		ConcurrentGet, ConcurrentInsert, ConcurrentMapInsert, ConcurrentPop, ConcurrentPush,
+		ConcurrentRemove, LockedItemRef,
+	},
+	Collection, Get, Insert, Len, MapInsert, PopFront, PushBack, Remove, SimpleCollectionRef,
+};
+use std::sync::{Mutex, MutexGuard, RwLock, RwLockReadGuard};
+
+impl<C: Collection + ?Sized> Collection for Mutex<C> {
+	type Item = C::Item;
+}
+
+impl<C: Len + ?Sized> Len for Mutex<C> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.lock().unwrap().len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.lock().unwrap().is_empty()
+	}
+}
+
+impl<T: Clone, C: SimpleCollectionRef + Get<T> + ?Sized> ConcurrentGet<T> for Mutex<C> {
+	type ItemRef<'a> = LockedItemRef<'a, C, MutexGuard<'a, C>, T> where Self: 'a;
+
+	#[inline(always)]
+	fn get(&self, key: T) -> Option<Self::ItemRef<'_>> {
+		let guard = self.lock().unwrap();
+		if guard.contains(key.clone()) {
+			Some(LockedItemRef::new(guard, key))
+		} else {
+			None
+		}
+	}
+
+	#[inline(always)]
+	fn contains(&self, key: T) -> bool {
+		self.lock().unwrap().contains(key)
+	}
+}
+
+impl<C: Insert + ?Sized> ConcurrentInsert for Mutex<C> {
+	type Output = C::Output;
+
+	#[inline(always)]
+	fn insert(&self, element: C::Item) -> C::Output {
+		self.lock().unwrap().insert(element)
+	}
+}
+
+impl<K, C: MapInsert<K> + ?Sized> ConcurrentMapInsert<K> for Mutex<C> {
+	type Output = C::Output;
+
+	#[inline(always)]
+	fn insert(&self, key: K, value: C::Item) -> C::Output {
+		self.lock().unwrap().insert(key, value)
+	}
+}
+
+impl<T, C: Remove<T> + ?Sized> ConcurrentRemove<T> for Mutex<C> {
+	#[inline(always)]
+	fn remove(&self, key: T) -> Option<C::Item> {
+		self.lock().unwrap().remove(key)
+	}
+}
+
+impl<C: PushBack + ?Sized> ConcurrentPush for Mutex<C> {
+	type Output = C::Output;
+
+	#[inline(always)]
+	fn push(&self, element: C::Item) -> C::Output {
+		self.lock().unwrap().push_back(element)
+	}
+}
+
+impl<C: PopFront + ?Sized> ConcurrentPop for Mutex<C> {
+	#[inline(always)]
+	fn pop(&self) -> Option<C::Item> {
+		self.lock().unwrap().pop_front()
+	}
+}
+
+impl<C: Collection + ?Sized> Collection for RwLock<C> {
+	type Item = C::Item;
+}
+
+impl<C: Len + ?Sized> Len for RwLock<C> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.read().unwrap().len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.read().unwrap().is_empty()
+	}
+}
+
+impl<T: Clone, C: SimpleCollectionRef + Get<T> + ?Sized> ConcurrentGet<T> for RwLock<C> {
+	type ItemRef<'a> = LockedItemRef<'a, C, RwLockReadGuard<'a, C>, T> where Self: 'a;
+
+	#[inline(always)]
+	fn get(&self, key: T) -> Option<Self::ItemRef<'_>> {
+		let guard = self.read().unwrap();
+		if guard.contains(key.clone()) {
+			Some(LockedItemRef::new(guard, key))
+		} else {
+			None
+		}
+	}
+
+	#[inline(always)]
+	fn contains(&self, key: T) -> bool {
+		self.read().unwrap().contains(key)
+	}
+}
+
+impl<C: Insert + ?Sized> ConcurrentInsert for RwLock<C> {
+	type Output = C::Output;
+
+	#[inline(always)]
+	fn insert(&self, element: C::Item) -> C::Output {
+		self.write().unwrap().insert(element)
+	}
+}
+
+impl<K, C: MapInsert<K> + ?Sized> ConcurrentMapInsert<K> for RwLock<C> {
+	type Output = C::Output;
+
+	#[inline(always)]
+	fn insert(&self, key: K, value: C::Item) -> C::Output {
+		self.write().unwrap().insert(key, value)
+	}
+}
+
+impl<T, C: Remove<T> + ?Sized> ConcurrentRemove<T> for RwLock<C> {
+	#[inline(always)]
+	fn remove(&self, key: T) -> Option<C::Item> {
+		self.write().unwrap().remove(key)
+	}
+}
+
+impl<C: PushBack + ?Sized> ConcurrentPush for RwLock<C> {
+	type Output = C::Output;
+
+	#[inline(always)]
+	fn push(&self, element: C::Item) -> C::Output {
+		self.write().unwrap().push_back(element)
+	}
+}
+
+impl<C: PopFront + ?Sized> ConcurrentPop for RwLock<C> {
+	#[inline(always)]
+	fn pop(&self) -> Option<C::Item> {
+		self.write().unwrap().pop_front()
+	}
+}
@@ -94,6 +94,90 @@
 //! As of version 0.8.0, those traits are also available without the `nightly`
 //! feature as regular trait definitions.
 //!
+//! # Derive macros
+//!
+//! By enabling the `derive` feature you can get access to derive macros
+//! implementing the traits of this crate for wrapper types,
+//! by forwarding them to a field designated with the
+//! `#[cc_traits(delegate = "field")]` attribute.
+//! ```ignore
+//! #[derive(Collection, CollectionRef, Len, Get, Iter)]
+//! #[cc_traits(delegate = "inner")]
+//! pub struct MyVec<T> {
+//!     inner: Vec<T>,
+//! }
+//! ```
+//!
+//! # Serde adapters
+//!
+//! By enabling the `serde` feature you can serialize and deserialize
+//! any collection implementing the traits of this crate, using the
+//! adapters of the [`serde`](crate::serde) module,
+//! either with the `#[serde(with = "cc_traits::serde::map")]` field attribute
+//! or by hand:
+//! ```
+//! # #[cfg(all(feature = "serde", feature = "alloc"))]
+//! # {
+//! use serde::{Serialize, Serializer};
+//! use std::collections::BTreeMap;
+//!
+//! pub struct Document {
+//!     fields: BTreeMap<&'static str, u32>,
+//! }
+//!
+//! impl Serialize for Document {
+//!     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+//!         cc_traits::serde::map::serialize(&self.fields, serializer)
+//!     }
+//! }
+//!
+//! #[cfg(feature = "serde_json")]
+//! {
+//!     let mut fields = BTreeMap::new();
+//!     fields.insert("a", 1);
+//!     let json = serde_json::to_string(&Document { fields }).unwrap();
+//!     assert_eq!(json, r#"{"a":1}"#);
+//! }
+//! # }
+//! ```
+//!
+//! # Trait objects
+//!
+//! Generic associated types make the traits of this crate unusable as trait
+//! objects. The [`dynamic`](crate::dynamic) module provides object-safe
+//! versions of the stack, set and map aliases (`DynStackMut`, `DynSetMut`
+//! and `DynMapMut`), implemented for any collection with simple references.
+//! ```
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! use cc_traits::dynamic::DynMapMut;
+//! use std::collections::BTreeMap;
+//!
+//! let mut maps: Vec<Box<dyn DynMapMut<String, u32>>> = vec![Box::new(BTreeMap::new())];
+//! #[cfg(feature = "std")]
+//! maps.push(Box::new(std::collections::HashMap::new()));
+//!
+//! for map in &mut maps {
+//!     map.insert("a".to_string(), 1);
+//!     assert_eq!(map.get(&"a".to_string()), Some(&1));
+//! }
+//! # }
+//! ```
+//!
+//! # Law checks
+//!
+//! By enabling the `testing` feature you can check that your own
+//! collections behave like the standard library ones, using the
+//! law-check functions of the [`testing`](crate::testing) module.
+//! ```
+//! # #[cfg(feature = "testing")]
+//! # {
+//! use std::collections::BTreeMap;
+//!
+//! cc_traits::testing::check_map_mut::<BTreeMap<String, u32>, String, u32>();
+//! # }
+//! ```
+//!
 //! # Standard library
 //!
 //! By default, all the traits defined in this crate are implemented (when relevant)
@@ -111,6 +195,12 @@
 //!   - [`smallvec`](https://crates.io/crates/smallvec) providing the `SmallVec` collection.
 //!   - [`serde_json`](https://crates.io/crates/serde_json) providing the `Map<String, Value>` collection for JSON objects.
 //!   - [`ijson`](https://crates.io/crates/ijson) providing the `IObject` and `IArray` collections.
+//!   - [`arrayvec`](https://crates.io/crates/arrayvec) providing the `ArrayVec` collection.
+//!   - [`heapless`](https://crates.io/crates/heapless) providing the `Vec`, `Deque`, `IndexMap` and `IndexSet` collections.
+//!   - [`indexmap`](https://crates.io/crates/indexmap) providing the `IndexMap` and `IndexSet` collections.
+//!   - [`hashbrown`](https://crates.io/crates/hashbrown) providing the `HashMap` and `HashSet` collections.
+//!   - [`dashmap`](https://crates.io/crates/dashmap) providing the `DashMap` and `DashSet` concurrent collections.
+//!   - [`crossbeam-queue`](https://crates.io/crates/crossbeam-queue) providing the `SegQueue` and `ArrayQueue` concurrent queues.
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(feature = "nightly", feature(trait_alias))]
 
@@ -118,8 +208,30 @@
 extern crate alloc;
 extern crate core;
 
+mod cursor;
+mod entry;
+mod error;
 mod impls;
 mod macros;
+pub mod views;
+
+#[cfg(feature = "alloc")]
+pub mod dynamic;
+
+pub mod concurrent;
+
+pub use cursor::*;
+pub use entry::*;
+pub use error::*;
+
+#[cfg(feature = "derive")]
+pub use cc_traits_derive::*;
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
+#[cfg(feature = "testing")]
+pub mod testing;
 
 #[cfg(feature = "nightly")]
 mod alias;
@@ -131,7 +243,10 @@ mod non_alias;
 #[cfg(not(feature = "nightly"))]
 pub use non_alias::*;
 
-use core::ops::{Deref, DerefMut};
+use core::{
+	cmp::Ordering,
+	ops::{Deref, DerefMut, RangeBounds},
+};
 
 /// Abstract collection.
 pub trait Collection {
@@ -302,6 +417,18 @@ pub trait GetKeyValueMut<T>: CollectionMut + KeyedRef {
 	fn get_key_value_mut(&mut self, key: T) -> Option<(Self::KeyRef<'_>, Self::ItemMut<'_>)>;
 }
 
+/// Map whose key-value pairs can be accessed by their position.
+pub trait GetIndex: CollectionRef + KeyedRef {
+	/// Returns the key-value pair stored at the given `index` (if any).
+	fn get_index(&self, index: usize) -> Option<(Self::KeyRef<'_>, Self::ItemRef<'_>)>;
+}
+
+/// Map whose values can be mutably accessed by their position.
+pub trait GetIndexMut: GetIndex + CollectionMut {
+	/// Returns the key and a mutable reference to the value stored at the given `index` (if any).
+	fn get_index_mut(&mut self, index: usize) -> Option<(Self::KeyRef<'_>, Self::ItemMut<'_>)>;
+}
+
 /// Collection exposing a reference to its front element.
 pub trait Front: CollectionRef {
 	/// Get a reference to the front element of the collection.
@@ -404,6 +531,26 @@ pub trait Remove<T>: Collection {
 	fn remove(&mut self, key: T) -> Option<Self::Item>;
 }
 
+/// Mutable map where key-value pairs can be removed from.
+pub trait RemoveEntry<T>: Keyed {
+	/// Remove the key-value pair identified by the given `key`, returning the stored key and value.
+	fn remove_entry(&mut self, key: T) -> Option<(Self::Key, Self::Item)>;
+}
+
+/// Key selecting the removal method that swaps the removed item with the last item.
+///
+/// Used with [`Remove`] on collections preserving the insertion order of their items.
+/// Removal is `O(1)`, but the order of the remaining items is perturbed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Swapping<Q>(pub Q);
+
+/// Key selecting the removal method that shifts all the items following the removed item.
+///
+/// Used with [`Remove`] on collections preserving the insertion order of their items.
+/// Removal is `O(n)`, but the order of the remaining items is preserved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Shifting<Q>(pub Q);
+
 /// Mutable collection where elements can be popped from the front.
 pub trait PopFront: Collection {
 	/// Remove the front element of the collection and return it (if any).
@@ -422,7 +569,370 @@ pub trait Clear {
 	fn clear(&mut self);
 }
 
+/// Sequence whose items are stored contiguously in memory.
+pub trait Contiguous: Collection {
+	/// Returns a slice containing all the items of the sequence, in order.
+	fn as_slice(&self) -> &[Self::Item];
+}
+
+/// Sequence whose items are stored contiguously in memory and can be mutated in place.
+pub trait ContiguousMut: Contiguous {
+	/// Returns a mutable slice containing all the items of the sequence, in order.
+	fn as_mut_slice(&mut self) -> &mut [Self::Item];
+}
+
+/// Sequence whose items are stored in two contiguous parts,
+/// such as a ring buffer.
+pub trait AsSlices: Collection {
+	/// Returns a pair of slices containing, in order, all the items of the sequence.
+	///
+	/// The first slice contains the front items, the second slice contains the back items.
+	fn as_slices(&self) -> (&[Self::Item], &[Self::Item]);
+}
+
+/// Sequence whose items are stored in two contiguous parts that can be mutated in place.
+pub trait AsSlicesMut: AsSlices {
+	/// Returns a pair of mutable slices containing, in order, all the items of the sequence.
+	///
+	/// See [`AsSlices::as_slices`] for more details.
+	fn as_mut_slices(&mut self) -> (&mut [Self::Item], &mut [Self::Item]);
+}
+
+/// Sequence that can be sorted in place, without preserving the order of equal items.
+///
+/// Implemented for every [`ContiguousMut`] sequence.
+pub trait SortUnstable: Collection {
+	/// Sorts the sequence, without preserving the order of equal items.
+	fn sort_unstable(&mut self)
+	where
+		Self::Item: Ord;
+
+	/// Sorts the sequence with a comparator function, without preserving the order of equal items.
+	fn sort_unstable_by<F>(&mut self, compare: F)
+	where
+		F: FnMut(&Self::Item, &Self::Item) -> Ordering;
+
+	/// Sorts the sequence with a key extraction function,
+	/// without preserving the order of equal items.
+	fn sort_unstable_by_key<K, F>(&mut self, f: F)
+	where
+		K: Ord,
+		F: FnMut(&Self::Item) -> K;
+}
+
+impl<C: ContiguousMut + ?Sized> SortUnstable for C {
+	#[inline(always)]
+	fn sort_unstable(&mut self)
+	where
+		Self::Item: Ord,
+	{
+		self.as_mut_slice().sort_unstable()
+	}
+
+	#[inline(always)]
+	fn sort_unstable_by<F>(&mut self, compare: F)
+	where
+		F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+	{
+		self.as_mut_slice().sort_unstable_by(compare)
+	}
+
+	#[inline(always)]
+	fn sort_unstable_by_key<K, F>(&mut self, f: F)
+	where
+		K: Ord,
+		F: FnMut(&Self::Item) -> K,
+	{
+		self.as_mut_slice().sort_unstable_by_key(f)
+	}
+}
+
+/// Sequence that can be sorted in place, preserving the order of equal items.
+///
+/// Implemented for every [`ContiguousMut`] sequence when the `alloc` feature is enabled.
+pub trait Sort: SortUnstable {
+	/// Sorts the sequence, preserving the order of equal items.
+	fn sort(&mut self)
+	where
+		Self::Item: Ord;
+
+	/// Sorts the sequence with a comparator function, preserving the order of equal items.
+	fn sort_by<F>(&mut self, compare: F)
+	where
+		F: FnMut(&Self::Item, &Self::Item) -> Ordering;
+
+	/// Sorts the sequence with a key extraction function, preserving the order of equal items.
+	fn sort_by_key<K, F>(&mut self, f: F)
+	where
+		K: Ord,
+		F: FnMut(&Self::Item) -> K;
+}
+
+#[cfg(feature = "alloc")]
+impl<C: ContiguousMut + ?Sized> Sort for C {
+	#[inline(always)]
+	fn sort(&mut self)
+	where
+		Self::Item: Ord,
+	{
+		self.as_mut_slice().sort()
+	}
+
+	#[inline(always)]
+	fn sort_by<F>(&mut self, compare: F)
+	where
+		F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+	{
+		self.as_mut_slice().sort_by(compare)
+	}
+
+	#[inline(always)]
+	fn sort_by_key<K, F>(&mut self, f: F)
+	where
+		K: Ord,
+		F: FnMut(&Self::Item) -> K,
+	{
+		self.as_mut_slice().sort_by_key(f)
+	}
+}
+
+/// Sorted sequence that can be binary searched.
+///
+/// If the sequence is not sorted, the returned result is unspecified and meaningless.
+pub trait BinarySearch: Collection {
+	/// Binary searches the sequence for the given item.
+	///
+	/// Returns `Ok` with the index of a matching item if any,
+	/// or `Err` with the index where the item could be inserted while maintaining the order.
+	fn binary_search(&self, x: &Self::Item) -> Result<usize, usize>
+	where
+		Self::Item: Ord;
+
+	/// Binary searches the sequence with a comparator function.
+	///
+	/// See [`BinarySearch::binary_search`] for more details.
+	fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+	where
+		F: FnMut(&Self::Item) -> Ordering;
+
+	/// Binary searches the sequence with a key extraction function.
+	///
+	/// See [`BinarySearch::binary_search`] for more details.
+	fn binary_search_by_key<K, F>(&self, key: &K, f: F) -> Result<usize, usize>
+	where
+		K: Ord,
+		F: FnMut(&Self::Item) -> K;
+}
+
+impl<C: Contiguous + ?Sized> BinarySearch for C {
+	#[inline(always)]
+	fn binary_search(&self, x: &Self::Item) -> Result<usize, usize>
+	where
+		Self::Item: Ord,
+	{
+		self.as_slice().binary_search(x)
+	}
+
+	#[inline(always)]
+	fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+	where
+		F: FnMut(&Self::Item) -> Ordering,
+	{
+		self.as_slice().binary_search_by(f)
+	}
+
+	#[inline(always)]
+	fn binary_search_by_key<K, F>(&self, key: &K, f: F) -> Result<usize, usize>
+	where
+		K: Ord,
+		F: FnMut(&Self::Item) -> K,
+	{
+		self.as_slice().binary_search_by_key(key, f)
+	}
+}
+
+/// Sequence whose order can be reversed in place.
+pub trait Reverse {
+	/// Reverses the order of the items in the sequence.
+	fn reverse(&mut self);
+}
+
+impl<C: ContiguousMut + ?Sized> Reverse for C {
+	#[inline(always)]
+	fn reverse(&mut self) {
+		self.as_mut_slice().reverse()
+	}
+}
+
+/// Sequence whose items can be rotated in place.
+pub trait Rotate {
+	/// Rotates the sequence in place such that the first `mid` items move to the end.
+	///
+	/// Panics if `mid` is greater than the length of the sequence.
+	fn rotate_left(&mut self, mid: usize);
+
+	/// Rotates the sequence in place such that the last `k` items move to the front.
+	///
+	/// Panics if `k` is greater than the length of the sequence.
+	fn rotate_right(&mut self, k: usize);
+}
+
+impl<C: ContiguousMut + ?Sized> Rotate for C {
+	#[inline(always)]
+	fn rotate_left(&mut self, mid: usize) {
+		self.as_mut_slice().rotate_left(mid)
+	}
+
+	#[inline(always)]
+	fn rotate_right(&mut self, k: usize) {
+		self.as_mut_slice().rotate_right(k)
+	}
+}
+
+/// Sequence whose consecutive repeated items can be removed.
+pub trait Dedup: Collection {
+	/// Removes consecutive repeated items.
+	fn dedup(&mut self)
+	where
+		Self::Item: PartialEq;
+
+	/// Removes consecutive items for which `same_bucket` returns `true`.
+	///
+	/// The function is passed the current item and the previous retained item,
+	/// in this order. The current item is removed if the function returns `true`.
+	fn dedup_by<F>(&mut self, same_bucket: F)
+	where
+		F: FnMut(&mut Self::Item, &mut Self::Item) -> bool;
+
+	/// Removes consecutive items resolving to the same key.
+	fn dedup_by_key<K, F>(&mut self, key: F)
+	where
+		K: PartialEq,
+		F: FnMut(&mut Self::Item) -> K;
+}
+
+impl<C: ContiguousMut + Truncate + ?Sized> Dedup for C {
+	#[inline(always)]
+	fn dedup(&mut self)
+	where
+		Self::Item: PartialEq,
+	{
+		self.dedup_by(|a, b| a == b)
+	}
+
+	#[inline(always)]
+	fn dedup_by<F>(&mut self, same_bucket: F)
+	where
+		F: FnMut(&mut Self::Item, &mut Self::Item) -> bool,
+	{
+		let len = dedup_slice_by(self.as_mut_slice(), same_bucket);
+		self.truncate(len)
+	}
+
+	#[inline(always)]
+	fn dedup_by_key<K, F>(&mut self, mut key: F)
+	where
+		K: PartialEq,
+		F: FnMut(&mut Self::Item) -> K,
+	{
+		self.dedup_by(|a, b| key(a) == key(b))
+	}
+}
+
+/// Moves the retained items of `slice` to its front, returning their count.
+///
+/// Removed items are left at the back of the slice, in an unspecified order.
+pub(crate) fn dedup_slice_by<T, F>(slice: &mut [T], mut same_bucket: F) -> usize
+where
+	F: FnMut(&mut T, &mut T) -> bool,
+{
+	if slice.is_empty() {
+		return 0;
+	}
+
+	let mut retained = 1;
+	for i in 1..slice.len() {
+		let (front, back) = slice.split_at_mut(i);
+		if !same_bucket(&mut back[0], &mut front[retained - 1]) {
+			slice.swap(retained, i);
+			retained += 1;
+		}
+	}
+
+	retained
+}
+
+/// Sequence where new items can be inserted at any position.
+pub trait InsertAt: Collection {
+	/// Inserts an item at position `index`, shifting all the items after it.
+	///
+	/// # Panics
+	///
+	/// Panics if `index` is greater than the length of the sequence.
+	fn insert_at(&mut self, index: usize, element: Self::Item);
+}
+
+/// Sequence where items can be removed in constant time.
+pub trait SwapRemove: Collection {
+	/// Removes the item at position `index` and returns it (if any).
+	///
+	/// The removed item is replaced by the last item of the sequence,
+	/// so the order of the items is not preserved.
+	fn swap_remove(&mut self, index: usize) -> Option<Self::Item>;
+}
+
+/// Sequence that can be shortened.
+pub trait Truncate {
+	/// Shortens the sequence, keeping the first `len` items and dropping the others.
+	///
+	/// Has no effect if `len` is greater than the length of the sequence.
+	fn truncate(&mut self, len: usize);
+}
+
+/// Sequence that can be resized by cloning a given item.
+pub trait Resize: Collection {
+	/// Resizes the sequence so that its length is equal to `new_len`.
+	///
+	/// If the sequence is extended, the new slots are filled with clones of `value`.
+	fn resize(&mut self, new_len: usize, value: Self::Item);
+}
+
+/// Sequence that can be resized by generating new items.
+pub trait ResizeWith: Collection {
+	/// Resizes the sequence so that its length is equal to `new_len`.
+	///
+	/// If the sequence is extended, the new slots are filled with the values returned by `f`.
+	fn resize_with<F>(&mut self, new_len: usize, f: F)
+	where
+		F: FnMut() -> Self::Item;
+}
+
+/// Sequence that can be split in two.
+pub trait SplitOff: Sized {
+	/// Splits the sequence in two at the given index.
+	///
+	/// Returns a new sequence containing the items in the range `[at, len)`.
+	/// After the call, `self` contains the items in the range `[0, at)`.
+	///
+	/// # Panics
+	///
+	/// Panics if `at` is greater than the length of the sequence.
+	fn split_off(&mut self, at: usize) -> Self;
+}
+
+/// Collection that can absorb another collection of the same type.
+pub trait Append {
+	/// Moves all the items of `other` into `self`, leaving `other` empty.
+	///
+	/// For maps, the values of `other` replace the values of `self` with the same key.
+	fn append(&mut self, other: &mut Self);
+}
+
 /// Iterable collection.
+///
+/// On maps, the iterator yields references to the values only, in the same order as
+/// [`MapIter::iter`] (which yields the key-value pairs) and [`Keys::keys`].
+/// Maps implementing [`Values`] return the same iterator from [`Values::values`].
 pub trait Iter: CollectionRef {
 	/// Iterator type.
 	type Iter<'a>: Iterator<Item = Self::ItemRef<'a>>
@@ -459,3 +969,484 @@ pub trait MapIterMut: KeyedRef + CollectionMut {
 
 	fn iter_mut(&mut self) -> Self::IterMut<'_>;
 }
+
+/// Map that can iterate over its keys.
+pub trait Keys: KeyedRef {
+	/// Key iterator type.
+	type Keys<'a>: Iterator<Item = Self::KeyRef<'a>>
+	where
+		Self: 'a;
+
+	/// Create an iterator over the keys of the map.
+	fn keys(&self) -> Self::Keys<'_>;
+}
+
+/// Map that can iterate over its values.
+pub trait Values: CollectionRef {
+	/// Value iterator type.
+	type Values<'a>: Iterator<Item = Self::ItemRef<'a>>
+	where
+		Self: 'a;
+
+	/// Create an iterator over the values of the map.
+	fn values(&self) -> Self::Values<'_>;
+}
+
+/// Map that can mutably iterate over its values.
+pub trait ValuesMut: CollectionMut {
+	/// Mutable value iterator type.
+	type ValuesMut<'a>: Iterator<Item = Self::ItemMut<'a>>
+	where
+		Self: 'a;
+
+	/// Create an iterator over mutable references to the values of the map.
+	fn values_mut(&mut self) -> Self::ValuesMut<'_>;
+}
+
+/// Map that can be consumed into an iterator over its keys.
+pub trait IntoKeys: Keyed {
+	/// Owned key iterator type.
+	type IntoKeys: Iterator<Item = Self::Key>;
+
+	/// Consumes the map and create an iterator over its keys.
+	fn into_keys(self) -> Self::IntoKeys;
+}
+
+/// Map that can be consumed into an iterator over its values.
+pub trait IntoValues: Keyed {
+	/// Owned value iterator type.
+	type IntoValues: Iterator<Item = Self::Item>;
+
+	/// Consumes the map and create an iterator over its values.
+	fn into_values(self) -> Self::IntoValues;
+}
+
+/// Set that can be combined with another set into their union.
+pub trait Union: CollectionRef {
+	/// Iterator over the union of two sets.
+	type Union<'a>: Iterator<Item = Self::ItemRef<'a>>
+	where
+		Self: 'a;
+
+	/// Returns an iterator over the items that are in `self` or in `other`, without duplicates.
+	fn union<'a>(&'a self, other: &'a Self) -> Self::Union<'a>;
+}
+
+/// Set that can absorb the items of another set in place.
+pub trait UnionWith {
+	/// Moves all the items of `other` into `self`.
+	fn union_with(&mut self, other: Self);
+}
+
+/// Set that can be combined with another set into their intersection.
+pub trait Intersection: CollectionRef {
+	/// Iterator over the intersection of two sets.
+	type Intersection<'a>: Iterator<Item = Self::ItemRef<'a>>
+	where
+		Self: 'a;
+
+	/// Returns an iterator over the items that are both in `self` and in `other`.
+	fn intersection<'a>(&'a self, other: &'a Self) -> Self::Intersection<'a>;
+}
+
+/// Set that can be restricted in place to its intersection with another set.
+pub trait RetainIn {
+	/// Only retains the items of `self` that are also in `other`.
+	fn retain_in(&mut self, other: &Self);
+}
+
+/// Set that can be combined with another set into their difference.
+pub trait Difference: CollectionRef {
+	/// Iterator over the difference of two sets.
+	type Difference<'a>: Iterator<Item = Self::ItemRef<'a>>
+	where
+		Self: 'a;
+
+	/// Returns an iterator over the items that are in `self` but not in `other`.
+	fn difference<'a>(&'a self, other: &'a Self) -> Self::Difference<'a>;
+}
+
+/// Set that can be replaced in place by its difference with another set.
+pub trait RemoveIn {
+	/// Removes from `self` all the items that are in `other`.
+	fn remove_in(&mut self, other: &Self);
+}
+
+/// Set that can be combined with another set into their symmetric difference.
+pub trait SymmetricDifference: CollectionRef {
+	/// Iterator over the symmetric difference of two sets.
+	type SymmetricDifference<'a>: Iterator<Item = Self::ItemRef<'a>>
+	where
+		Self: 'a;
+
+	/// Returns an iterator over the items that are in `self` or in `other`, but not in both.
+	fn symmetric_difference<'a>(&'a self, other: &'a Self) -> Self::SymmetricDifference<'a>;
+}
+
+/// Set that can be replaced in place by its symmetric difference with another set.
+pub trait SymmetricDifferenceWith {
+	/// Replaces `self` with the symmetric difference of `self` and `other`.
+	fn symmetric_difference_with(&mut self, other: Self);
+}
+
+/// Set that can be compared with another set for inclusion.
+pub trait IsSubset {
+	/// Checks if every item of `self` is also in `other`.
+	fn is_subset(&self, other: &Self) -> bool;
+
+	/// Checks if every item of `other` is also in `self`.
+	fn is_superset(&self, other: &Self) -> bool {
+		other.is_subset(self)
+	}
+}
+
+/// Set that can be compared with another set for disjointness.
+pub trait IsDisjoint {
+	/// Checks if `self` and `other` have no item in common.
+	fn is_disjoint(&self, other: &Self) -> bool;
+}
+
+/// Ordered collection.
+///
+/// The items of an ordered collection (or its keys for maps) are sorted.
+/// Iterating over such collection (with [`Iter`] or [`MapIter`])
+/// yields the items in ascending order.
+pub trait Ordered {}
+
+/// Ordered collection whose items can be iterated over within a range.
+pub trait Range<Q: ?Sized, R: RangeBounds<Q>>: CollectionRef {
+	/// Iterator type.
+	type Range<'a>: Iterator<Item = Self::ItemRef<'a>>
+	where
+		Self: 'a;
+
+	/// Create an iterator over the items of the collection within the given range.
+	fn range(&self, range: R) -> Self::Range<'_>;
+}
+
+/// Ordered map whose key-value pairs can be iterated over within a range of keys.
+pub trait MapRange<Q: ?Sized, R: RangeBounds<Q>>: KeyedRef + CollectionRef {
+	/// Iterator type.
+	type Range<'a>: Iterator<Item = (Self::KeyRef<'a>, Self::ItemRef<'a>)>
+	where
+		Self: 'a;
+
+	/// Create an iterator over the key-value pairs of the map whose key is within the given range.
+	fn range(&self, range: R) -> Self::Range<'_>;
+}
+
+/// Ordered collection exposing a reference to its first (minimum) element.
+pub trait First: CollectionRef {
+	/// Get a reference to the first element of the collection.
+	fn first(&self) -> Option<Self::ItemRef<'_>>;
+}
+
+/// Ordered collection exposing a reference to its last (maximum) element.
+pub trait Last: CollectionRef {
+	/// Get a reference to the last element of the collection.
+	fn last(&self) -> Option<Self::ItemRef<'_>>;
+}
+
+/// Ordered map exposing its first key-value pair.
+pub trait FirstKeyValue: KeyedRef + CollectionRef {
+	/// Returns the key-value pair with the minimum key in the map.
+	fn first_key_value(&self) -> Option<(Self::KeyRef<'_>, Self::ItemRef<'_>)>;
+}
+
+/// Ordered map exposing its last key-value pair.
+pub trait LastKeyValue: KeyedRef + CollectionRef {
+	/// Returns the key-value pair with the maximum key in the map.
+	fn last_key_value(&self) -> Option<(Self::KeyRef<'_>, Self::ItemRef<'_>)>;
+}
+
+/// Mutable ordered collection where the first element can be popped.
+pub trait PopFirst: Collection {
+	/// Remove the first element of the collection and return it (if any).
+	fn pop_first(&mut self) -> Option<Self::Item>;
+}
+
+/// Mutable ordered collection where the last element can be popped.
+pub trait PopLast: Collection {
+	/// Remove the last element of the collection and return it (if any).
+	fn pop_last(&mut self) -> Option<Self::Item>;
+}
+
+/// Mutable ordered map where the first key-value pair can be popped.
+pub trait PopFirstKeyValue: Keyed {
+	/// Remove the key-value pair with the minimum key in the map and return it (if any).
+	fn pop_first_key_value(&mut self) -> Option<(Self::Key, Self::Item)>;
+}
+
+/// Mutable ordered map where the last key-value pair can be popped.
+pub trait PopLastKeyValue: Keyed {
+	/// Remove the key-value pair with the maximum key in the map and return it (if any).
+	fn pop_last_key_value(&mut self) -> Option<(Self::Key, Self::Item)>;
+}
+
+/// Ordered collection where the predecessor of a key can be queried.
+pub trait Floor<T>: CollectionRef {
+	/// Returns a reference to the greatest item less than or equal to the given key (if any).
+	fn floor(&self, key: T) -> Option<Self::ItemRef<'_>>;
+}
+
+/// Ordered collection where the successor of a key can be queried.
+pub trait Ceiling<T>: CollectionRef {
+	/// Returns a reference to the least item greater than or equal to the given key (if any).
+	fn ceiling(&self, key: T) -> Option<Self::ItemRef<'_>>;
+}
+
+/// Ordered map where the predecessor of a key can be queried.
+pub trait FloorKeyValue<T>: KeyedRef + CollectionRef {
+	/// Returns the key-value pair with the greatest key less than or equal to the given `key` (if any).
+	fn floor_key_value(&self, key: T) -> Option<(Self::KeyRef<'_>, Self::ItemRef<'_>)>;
+}
+
+/// Ordered map where the successor of a key can be queried.
+pub trait CeilingKeyValue<T>: KeyedRef + CollectionRef {
+	/// Returns the key-value pair with the least key greater than or equal to the given `key` (if any).
+	fn ceiling_key_value(&self, key: T) -> Option<(Self::KeyRef<'_>, Self::ItemRef<'_>)>;
+}
+
+/// Priority queue exposing a reference to its greatest item.
+pub trait Peek: CollectionRef {
+	/// Get a reference to the greatest item of the queue (if any).
+	fn peek(&self) -> Option<Self::ItemRef<'_>>;
+}
+
+/// Priority queue exposing a mutable reference to its greatest item.
+///
+/// The queue is reordered when the mutable reference is dropped,
+/// so the item may not be the greatest anymore after modification.
+pub trait PeekMut: CollectionMut {
+	/// Get a mutable reference to the greatest item of the queue (if any).
+	fn peek_mut(&mut self) -> Option<Self::ItemMut<'_>>;
+}
+
+/// Priority queue where new items can be pushed.
+///
+/// Queues that keep every pushed item, such as `BinaryHeap`, have `()` as
+/// [`Output`](PushPriority::Output). Queues that store at most one copy of
+/// each item, such as `BTreeSet`, return whether the item was added:
+/// pushing an item equal to one already queued does nothing.
+/// Only the former are [`PriorityQueueMut`].
+pub trait PushPriority: Collection {
+	/// The output of the push function.
+	type Output;
+
+	/// Push a new item in the queue, at the position given by its priority.
+	fn push(&mut self, element: Self::Item) -> Self::Output;
+}
+
+/// Priority queue where the greatest item can be popped.
+pub trait PopMax: Collection {
+	/// Remove the greatest item of the queue and return it (if any).
+	fn pop_max(&mut self) -> Option<Self::Item>;
+}
+
+/// Collection that can be drained.
+pub trait Drain: Collection {
+	/// Draining iterator type.
+	type Drain<'a>: Iterator<Item = Self::Item>
+	where
+		Self: 'a;
+
+	/// Remove all the items of the collection, returning them as an iterator.
+	///
+	/// The collection is left empty, even if the iterator is not fully consumed.
+	fn drain(&mut self) -> Self::Drain<'_>;
+}
+
+/// Map whose key-value pairs can be drained.
+pub trait MapDrain: Keyed {
+	/// Draining iterator type.
+	type Drain<'a>: Iterator<Item = (Self::Key, Self::Item)>
+	where
+		Self: 'a;
+
+	/// Remove all the key-value pairs of the map, returning them as an iterator.
+	///
+	/// The map is left empty, even if the iterator is not fully consumed.
+	fn drain(&mut self) -> Self::Drain<'_>;
+}
+
+/// Sequence whose items can be drained within a range of indexes.
+pub trait DrainRange<R: RangeBounds<usize>>: Collection {
+	/// Draining iterator type.
+	type DrainRange<'a>: Iterator<Item = Self::Item>
+	where
+		Self: 'a;
+
+	/// Remove the items of the sequence within the given range, returning them as an iterator.
+	///
+	/// # Panics
+	///
+	/// Panics if the range is out of bounds.
+	fn drain_range(&mut self, range: R) -> Self::DrainRange<'_>;
+}
+
+/// Collection that can be filtered in place.
+pub trait Retain: Collection {
+	/// Only retains the items for which the given predicate returns `true`.
+	fn retain<F>(&mut self, f: F)
+	where
+		F: FnMut(&Self::Item) -> bool;
+}
+
+/// Map whose key-value pairs can be filtered in place.
+pub trait MapRetain: Keyed {
+	/// Only retains the key-value pairs for which the given predicate returns `true`.
+	fn retain<F>(&mut self, f: F)
+	where
+		F: FnMut(&Self::Key, &mut Self::Item) -> bool;
+}
+
+/// Collection that can be consumed into an iterator over its items.
+pub trait IntoItems: Collection {
+	/// Owned iterator type.
+	type IntoItems: Iterator<Item = Self::Item>;
+
+	/// Consumes the collection and create an iterator over its items.
+	fn into_items(self) -> Self::IntoItems;
+}
+
+/// Map that can be consumed into an iterator over its key-value pairs.
+pub trait MapIntoItems: Keyed {
+	/// Owned iterator type.
+	type IntoItems: Iterator<Item = (Self::Key, Self::Item)>;
+
+	/// Consumes the map and create an iterator over its key-value pairs.
+	fn into_items(self) -> Self::IntoItems;
+}
+
+/// Collection that can be extended with the items of an iterator.
+///
+/// Implementations reserve enough capacity for the lower bound of the
+/// iterator's size hint when the collection supports it.
+pub trait ExtendItems: Collection {
+	/// Extends the collection with the items of the given iterator.
+	fn extend_items<I>(&mut self, items: I)
+	where
+		I: IntoIterator<Item = Self::Item>;
+}
+
+/// Map that can be extended with the key-value pairs of an iterator.
+///
+/// Implementations reserve enough capacity for the lower bound of the
+/// iterator's size hint when the map supports it.
+pub trait ExtendMap<K>: Collection {
+	/// Extends the map with the key-value pairs of the given iterator.
+	///
+	/// Existing values are replaced, just like with [`MapInsert::insert`].
+	fn extend_map<I>(&mut self, items: I)
+	where
+		I: IntoIterator<Item = (K, Self::Item)>;
+}
+
+/// Collection that can be created from the items of an iterator.
+///
+/// Implemented for every extendable collection with [`WithCapacity`],
+/// which is created with the lower bound of the iterator's size hint.
+pub trait FromItems: Collection {
+	/// Creates a new collection from the items of the given iterator.
+	fn from_items<I>(items: I) -> Self
+	where
+		I: IntoIterator<Item = Self::Item>;
+}
+
+impl<C: WithCapacity + ExtendItems> FromItems for C {
+	#[inline(always)]
+	fn from_items<I>(items: I) -> Self
+	where
+		I: IntoIterator<Item = Self::Item>,
+	{
+		let items = items.into_iter();
+		let mut result = Self::with_capacity(items.size_hint().0);
+		result.extend_items(items);
+		result
+	}
+}
+
+/// Map that can be created from the key-value pairs of an iterator.
+///
+/// Implemented for every extendable map with [`WithCapacity`],
+/// which is created with the lower bound of the iterator's size hint.
+pub trait FromMap<K>: Collection {
+	/// Creates a new map from the key-value pairs of the given iterator.
+	fn from_map<I>(items: I) -> Self
+	where
+		I: IntoIterator<Item = (K, Self::Item)>;
+}
+
+impl<K, C: WithCapacity + ExtendMap<K>> FromMap<K> for C {
+	#[inline(always)]
+	fn from_map<I>(items: I) -> Self
+	where
+		I: IntoIterator<Item = (K, Self::Item)>,
+	{
+		let items = items.into_iter();
+		let mut result = Self::with_capacity(items.size_hint().0);
+		result.extend_map(items);
+		result
+	}
+}
+
+/// Collection that can try to extend its capacity without aborting on allocation failure.
+pub trait TryReserve {
+	/// Error returned when the capacity cannot be reserved.
+	type Error;
+
+	/// Tries to reserve enough memory for `additional` more elements.
+	fn try_reserve(&mut self, additional: usize) -> Result<(), Self::Error>;
+}
+
+/// Collection that can try to be created with a minimum given capacity
+/// without aborting on allocation failure.
+pub trait TryWithCapacity: Sized {
+	/// Error returned when the capacity cannot be allocated.
+	type Error;
+
+	/// Tries to create a new instance of `Self` with the given minimum capacity.
+	fn try_with_capacity(capacity: usize) -> Result<Self, Self::Error>;
+}
+
+/// Mutable collection where new elements can be pushed on the back
+/// without aborting on allocation failure.
+pub trait TryPushBack: Collection {
+	/// Error returned when the element cannot be pushed.
+	type Error;
+
+	/// Tries to push a new element on the back of the collection.
+	///
+	/// The collection is left unchanged on failure.
+	fn try_push_back(&mut self, element: Self::Item) -> Result<(), Self::Error>;
+}
+
+/// Mutable collection where new elements can be inserted
+/// without aborting on allocation failure.
+pub trait TryInsert: Collection {
+	/// The output of the insertion function.
+	type Output;
+
+	/// Error returned when the element cannot be inserted.
+	type Error;
+
+	/// Tries to insert a new element in the collection.
+	///
+	/// The collection is left unchanged on failure.
+	fn try_insert(&mut self, element: Self::Item) -> Result<Self::Output, Self::Error>;
+}
+
+/// Mutable map where new key-value pairs can be inserted
+/// without aborting on allocation failure.
+pub trait MapTryInsert<K>: Collection {
+	/// The output of the insertion function.
+	type Output;
+
+	/// Error returned when the key-value pair cannot be inserted.
+	type Error;
+
+	/// Tries to insert a new key-value pair in the map.
+	///
+	/// The map is left unchanged on failure.
+	fn try_insert(&mut self, key: K, value: Self::Item) -> Result<Self::Output, Self::Error>;
+}
@@ -1,5 +1,5 @@
 use crate::*;
-use core::ops::{Index, IndexMut};
+use core::ops::{Bound, Index, IndexMut};
 
 /// Collection with mutable capacity.
 pub trait CapacityMut: Capacity + Reserve {}
@@ -38,9 +38,15 @@ impl<T, C: Stack<T> + Index<usize, Output = T>> Vec<T> for C {}
 ///
 /// This trait alias describes the mutables operations on a Vec.
 /// See [`Vec`] for more details.
-pub trait VecMut<T>: Vec<T> + StackMut<T> + IndexMut<usize> {}
+pub trait VecMut<T>:
+	Vec<T> + StackMut<T> + IndexMut<usize> + InsertAt + SwapRemove + Truncate
+{
+}
 
-impl<T, C: Vec<T> + StackMut<T> + IndexMut<usize>> VecMut<T> for C {}
+impl<T, C: Vec<T> + StackMut<T> + IndexMut<usize> + InsertAt + SwapRemove + Truncate> VecMut<T>
+	for C
+{
+}
 
 /// Immutable double-ended queue.
 ///
@@ -89,6 +95,75 @@ pub trait SetMut<T>: Set<T> + Insert<Output = bool> + for<'a> Remove<&'a T> {}
 
 impl<T, C: Set<T> + Insert<Output = bool> + for<'a> Remove<&'a T>> SetMut<T> for C {}
 
+/// Immutable set data structure supporting set algebra operations.
+///
+/// Provides lazy iterators over the union, intersection, difference and
+/// symmetric difference of two sets, and inclusion tests between sets.
+pub trait SetAlgebra<T>:
+	Set<T> + Union + Intersection + Difference + SymmetricDifference + IsSubset + IsDisjoint
+{
+}
+
+impl<
+		T,
+		C: Set<T> + Union + Intersection + Difference + SymmetricDifference + IsSubset + IsDisjoint,
+	> SetAlgebra<T> for C
+{
+}
+
+/// Mutable set data structure supporting set algebra operations.
+///
+/// In addition to [`SetAlgebra`], the set can be combined in place
+/// with another set.
+pub trait SetAlgebraMut<T>:
+	SetAlgebra<T> + SetMut<T> + UnionWith + RetainIn + RemoveIn + SymmetricDifferenceWith
+{
+}
+
+impl<
+		T,
+		C: SetAlgebra<T> + SetMut<T> + UnionWith + RetainIn + RemoveIn + SymmetricDifferenceWith,
+	> SetAlgebraMut<T> for C
+{
+}
+
+/// Immutable ordered set data structure.
+///
+/// An ordered set is a [`Set`] whose items are sorted,
+/// providing range queries and predecessor/successor lookups.
+pub trait OrderedSet<T>:
+	Set<T>
+	+ Ordered
+	+ Iter
+	+ First
+	+ Last
+	+ for<'a> Floor<&'a T>
+	+ for<'a> Ceiling<&'a T>
+	+ for<'a> Range<T, (Bound<&'a T>, Bound<&'a T>)>
+{
+}
+
+impl<
+		T,
+		C: Set<T>
+			+ Ordered
+			+ Iter
+			+ First
+			+ Last
+			+ for<'a> Floor<&'a T>
+			+ for<'a> Ceiling<&'a T>
+			+ for<'a> Range<T, (Bound<&'a T>, Bound<&'a T>)>,
+	> OrderedSet<T> for C
+{
+}
+
+/// Mutable ordered set data structure.
+///
+/// See [`OrderedSet`] for more details.
+pub trait OrderedSetMut<T>: OrderedSet<T> + SetMut<T> + PopFirst + PopLast {}
+
+impl<T, C: OrderedSet<T> + SetMut<T> + PopFirst + PopLast> OrderedSetMut<T> for C {}
+
 /// Imutable map data structure.
 ///
 /// A map is an unordered collection storing key-value pairs, indexed by the key.
@@ -104,7 +179,11 @@ impl<K, V, C: Keyed<Key = K, Item = V> + Len + for<'a> Get<&'a K> + for<'a> GetK
 
 /// Mutable map data structure.
 pub trait MapMut<K, V>:
-	Map<K, V> + for<'a> GetMut<&'a K> + MapInsert<K, Output = Option<V>> + for<'a> Remove<&'a K>
+	Map<K, V>
+	+ for<'a> GetMut<&'a K>
+	+ MapInsert<K, Output = Option<V>>
+	+ for<'a> Remove<&'a K>
+	+ for<'a> RemoveEntry<&'a K>
 {
 }
 
@@ -114,11 +193,74 @@ impl<
 		C: Map<K, V>
 			+ for<'a> GetMut<&'a K>
 			+ MapInsert<K, Output = Option<V>>
-			+ for<'a> Remove<&'a K>,
+			+ for<'a> Remove<&'a K>
+			+ for<'a> RemoveEntry<&'a K>,
 	> MapMut<K, V> for C
 {
 }
 
+/// Immutable ordered map data structure.
+///
+/// An ordered map is a [`Map`] whose keys are sorted,
+/// providing range queries and predecessor/successor lookups.
+pub trait OrderedMap<K, V>:
+	Map<K, V>
+	+ Ordered
+	+ MapIter
+	+ FirstKeyValue
+	+ LastKeyValue
+	+ for<'a> FloorKeyValue<&'a K>
+	+ for<'a> CeilingKeyValue<&'a K>
+	+ for<'a> MapRange<K, (Bound<&'a K>, Bound<&'a K>)>
+{
+}
+
+impl<
+		K,
+		V,
+		C: Map<K, V>
+			+ Ordered
+			+ MapIter
+			+ FirstKeyValue
+			+ LastKeyValue
+			+ for<'a> FloorKeyValue<&'a K>
+			+ for<'a> CeilingKeyValue<&'a K>
+			+ for<'a> MapRange<K, (Bound<&'a K>, Bound<&'a K>)>,
+	> OrderedMap<K, V> for C
+{
+}
+
+/// Mutable ordered map data structure.
+///
+/// See [`OrderedMap`] for more details.
+pub trait OrderedMapMut<K, V>:
+	OrderedMap<K, V> + MapMut<K, V> + PopFirstKeyValue + PopLastKeyValue
+{
+}
+
+impl<K, V, C: OrderedMap<K, V> + MapMut<K, V> + PopFirstKeyValue + PopLastKeyValue>
+	OrderedMapMut<K, V> for C
+{
+}
+
+/// Immutable priority queue data structure.
+///
+/// A priority queue provides two main operations:
+///   - [`PushPriority::push`], which adds an element to the queue, and
+///   - [`PopMax::pop_max`], which removes the greatest element of the queue.
+pub trait PriorityQueue<T>: Collection<Item = T> + Len + Peek {}
+
+impl<T, C: Collection<Item = T> + Len + Peek> PriorityQueue<T> for C {}
+
+/// Mutable priority queue data structure.
+///
+/// Every pushed item is kept in the queue, even if an equal item is
+/// already queued. Deduplicating queues (see [`PushPriority`]) do not
+/// implement this trait.
+pub trait PriorityQueueMut<T>: PriorityQueue<T> + PushPriority<Output = ()> + PopMax {}
+
+impl<T, C: PriorityQueue<T> + PushPriority<Output = ()> + PopMax> PriorityQueueMut<T> for C {}
+
 /// Imutable slab data structure.
 ///
 /// A slab is a linear collection storing each element at a given index.
@@ -0,0 +1,301 @@
+//! Serde adapters for any collection implementing the traits of this crate.
+//!
+//! The [`seq`], [`set`] and [`map`] modules can be used with the
+//! `#[serde(with = "...")]` attribute, and the [`SerializeSeq`],
+//! [`SerializeSet`] and [`SerializeMap`] wrappers implement `Serialize` and
+//! `Deserialize` for the wrapped collection.
+//!
+//! Serialization goes through [`Iter`] (resp. [`MapIter`]), and
+//! deserialization through [`WithCapacity`] and [`PushBack`]
+//! (resp. [`Insert`] and [`MapInsert`]).
+//! ```
+//! use cc_traits::serde::SerializeSeq;
+//! use serde::{Deserialize, Deserializer, Serialize, Serializer};
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! use std::collections::VecDeque;
+//!
+//! // Equivalent to `#[serde(with = "cc_traits::serde::seq")]` on the field.
+//! pub struct Document<T> {
+//!     items: VecDeque<T>,
+//! }
+//!
+//! impl<T: Serialize> Serialize for Document<T> {
+//!     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+//!         cc_traits::serde::seq::serialize(&self.items, serializer)
+//!     }
+//! }
+//!
+//! impl<'de, T: Deserialize<'de>> Deserialize<'de> for Document<T> {
+//!     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+//!         cc_traits::serde::seq::deserialize(deserializer).map(|items| Document { items })
+//!     }
+//! }
+//!
+//! #[cfg(feature = "serde_json")]
+//! {
+//!     let document: Document<u32> = serde_json::from_str("[1, 2, 3]").unwrap();
+//!     assert_eq!(document.items, [1, 2, 3]);
+//!     assert_eq!(serde_json::to_string(&document).unwrap(), "[1,2,3]");
+//!     assert_eq!(serde_json::to_string(&SerializeSeq(document.items)).unwrap(), "[1,2,3]");
+//! }
+//! # }
+//! ```
+use crate::{Insert, Iter, Keyed, MapInsert, MapIter, PushBack, WithCapacity};
+use core::{fmt, marker::PhantomData};
+use ::serde::{
+	de::{MapAccess, SeqAccess, Visitor},
+	Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// Maximum number of bytes preallocated from a size hint when deserializing.
+const MAX_PREALLOCATED_BYTES: usize = 1024 * 1024;
+
+/// Returns the capacity to preallocate for the given size hint.
+///
+/// The size hint is provided by the input, and cannot be trusted.
+fn cautious<T>(hint: Option<usize>) -> usize {
+	let item_size = core::mem::size_of::<T>().max(1);
+	hint.unwrap_or(0).min(MAX_PREALLOCATED_BYTES / item_size)
+}
+
+/// Serialize and deserialize a sequence.
+///
+/// Items are deserialized in order, and pushed with [`PushBack`].
+pub mod seq {
+	use super::*;
+
+	/// Serializes the items of the given collection as a sequence.
+	pub fn serialize<'c, C, S>(collection: &'c C, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		C: Iter + ?Sized,
+		C::ItemRef<'c>: Serialize,
+		S: Serializer,
+	{
+		serializer.collect_seq(Iter::iter(collection))
+	}
+
+	/// Deserializes a sequence into a new collection.
+	pub fn deserialize<'de, C, D>(deserializer: D) -> Result<C, D::Error>
+	where
+		C: WithCapacity + PushBack,
+		C::Item: Deserialize<'de>,
+		D: Deserializer<'de>,
+	{
+		struct SeqVisitor<C>(PhantomData<C>);
+
+		impl<'de, C> Visitor<'de> for SeqVisitor<C>
+		where
+			C: WithCapacity + PushBack,
+			C::Item: Deserialize<'de>,
+		{
+			type Value = C;
+
+			fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				f.write_str("a sequence")
+			}
+
+			fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<C, A::Error> {
+				let mut collection = C::with_capacity(cautious::<C::Item>(seq.size_hint()));
+				while let Some(item) = seq.next_element()? {
+					collection.push_back(item);
+				}
+
+				Ok(collection)
+			}
+		}
+
+		deserializer.deserialize_seq(SeqVisitor(PhantomData))
+	}
+}
+
+/// Serialize and deserialize a set.
+///
+/// Sets are represented as sequences. Items are deserialized and
+/// added with [`Insert`].
+pub mod set {
+	use super::*;
+
+	/// Serializes the items of the given collection as a sequence.
+	pub fn serialize<'c, C, S>(collection: &'c C, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		C: Iter + ?Sized,
+		C::ItemRef<'c>: Serialize,
+		S: Serializer,
+	{
+		serializer.collect_seq(Iter::iter(collection))
+	}
+
+	/// Deserializes a sequence into a new collection.
+	pub fn deserialize<'de, C, D>(deserializer: D) -> Result<C, D::Error>
+	where
+		C: WithCapacity + Insert,
+		C::Item: Deserialize<'de>,
+		D: Deserializer<'de>,
+	{
+		struct SetVisitor<C>(PhantomData<C>);
+
+		impl<'de, C> Visitor<'de> for SetVisitor<C>
+		where
+			C: WithCapacity + Insert,
+			C::Item: Deserialize<'de>,
+		{
+			type Value = C;
+
+			fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				f.write_str("a sequence")
+			}
+
+			fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<C, A::Error> {
+				let mut collection = C::with_capacity(cautious::<C::Item>(seq.size_hint()));
+				while let Some(item) = seq.next_element()? {
+					collection.insert(item);
+				}
+
+				Ok(collection)
+			}
+		}
+
+		deserializer.deserialize_seq(SetVisitor(PhantomData))
+	}
+}
+
+/// Serialize and deserialize a map.
+///
+/// Key-value pairs are deserialized and added with [`MapInsert`].
+pub mod map {
+	use super::*;
+
+	/// Serializes the key-value pairs of the given collection as a map.
+	pub fn serialize<'c, C, S>(collection: &'c C, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		C: MapIter + ?Sized,
+		C::KeyRef<'c>: Serialize,
+		C::ItemRef<'c>: Serialize,
+		S: Serializer,
+	{
+		serializer.collect_map(MapIter::iter(collection))
+	}
+
+	/// Deserializes a map into a new collection.
+	pub fn deserialize<'de, C, D>(deserializer: D) -> Result<C, D::Error>
+	where
+		C: WithCapacity + Keyed + MapInsert<<C as Keyed>::Key>,
+		C::Key: Deserialize<'de>,
+		C::Item: Deserialize<'de>,
+		D: Deserializer<'de>,
+	{
+		struct MapVisitor<C>(PhantomData<C>);
+
+		impl<'de, C> Visitor<'de> for MapVisitor<C>
+		where
+			C: WithCapacity + Keyed + MapInsert<<C as Keyed>::Key>,
+			C::Key: Deserialize<'de>,
+			C::Item: Deserialize<'de>,
+		{
+			type Value = C;
+
+			fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				f.write_str("a map")
+			}
+
+			fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<C, A::Error> {
+				let hint = cautious::<(C::Key, C::Item)>(map.size_hint());
+				let mut collection = C::with_capacity(hint);
+				while let Some((key, value)) = map.next_entry()? {
+					collection.insert(key, value);
+				}
+
+				Ok(collection)
+			}
+		}
+
+		deserializer.deserialize_map(MapVisitor(PhantomData))
+	}
+}
+
+/// Wrapper serializing and deserializing a collection as a sequence.
+///
+/// See the [`seq`] module for more details.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SerializeSeq<C>(pub C);
+
+impl<C> Serialize for SerializeSeq<C>
+where
+	C: Iter,
+	for<'a> C::ItemRef<'a>: Serialize,
+{
+	#[inline(always)]
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		seq::serialize(&self.0, serializer)
+	}
+}
+
+impl<'de, C> Deserialize<'de> for SerializeSeq<C>
+where
+	C: WithCapacity + PushBack,
+	C::Item: Deserialize<'de>,
+{
+	#[inline(always)]
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		seq::deserialize(deserializer).map(SerializeSeq)
+	}
+}
+
+/// Wrapper serializing and deserializing a collection as a set.
+///
+/// See the [`set`] module for more details.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SerializeSet<C>(pub C);
+
+impl<C> Serialize for SerializeSet<C>
+where
+	C: Iter,
+	for<'a> C::ItemRef<'a>: Serialize,
+{
+	#[inline(always)]
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		set::serialize(&self.0, serializer)
+	}
+}
+
+impl<'de, C> Deserialize<'de> for SerializeSet<C>
+where
+	C: WithCapacity + Insert,
+	C::Item: Deserialize<'de>,
+{
+	#[inline(always)]
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		set::deserialize(deserializer).map(SerializeSet)
+	}
+}
+
+/// Wrapper serializing and deserializing a collection as a map.
+///
+/// See the [`map`] module for more details.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SerializeMap<C>(pub C);
+
+impl<C> Serialize for SerializeMap<C>
+where
+	C: MapIter,
+	for<'a> C::KeyRef<'a>: Serialize,
+	for<'a> C::ItemRef<'a>: Serialize,
+{
+	#[inline(always)]
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		map::serialize(&self.0, serializer)
+	}
+}
+
+impl<'de, C> Deserialize<'de> for SerializeMap<C>
+where
+	C: WithCapacity + Keyed + MapInsert<<C as Keyed>::Key>,
+	C::Key: Deserialize<'de>,
+	C::Item: Deserialize<'de>,
+{
+	#[inline(always)]
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		map::deserialize(deserializer).map(SerializeMap)
+	}
+}
@@ -0,0 +1,400 @@
+//! Law checks for implementors of the traits of this crate.
+//!
+//! Each `check_*` function drives a new (`Default`) collection through
+//! deterministic sequences of pseudo-randomly generated operations, and
+//! compares every result against a simple reference model.
+//! On the first mismatch it panics, naming the operation, the seed and the
+//! step that failed. The functions are meant to be called from tests:
+//!
+//! ```
+//! use cc_traits::testing::check_stack_mut;
+//!
+//! // #[test]
+//! fn stack_laws() {
+//!     check_stack_mut::<Vec<u32>, u32>();
+//! }
+//! # stack_laws();
+//! ```
+//!
+//! Items are produced by the [`Generate`] trait. They are drawn from a
+//! small domain, so that most operations target items already present
+//! in the collection.
+use crate::{Iter, MapInsert, MapIter, MapMut, SetMut, SlabMut, StackMut};
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+/// Number of operation sequences run by each check.
+const SEEDS: u64 = 16;
+
+/// Number of operations in each sequence.
+const STEPS: usize = 200;
+
+/// Size of the domain in which items are generated.
+const DOMAIN: u64 = 16;
+
+/// Deterministic pseudo-random number generator (SplitMix64).
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+	/// Creates a new generator from the given seed.
+	pub fn new(seed: u64) -> Self {
+		Self(seed)
+	}
+
+	/// Returns the next pseudo-random number.
+	pub fn next_u64(&mut self) -> u64 {
+		self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+		let mut z = self.0;
+		z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+		z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+		z ^ (z >> 31)
+	}
+
+	/// Returns a pseudo-random number in `0..n`.
+	///
+	/// # Panics
+	///
+	/// Panics if `n` is zero.
+	pub fn below(&mut self, n: u64) -> u64 {
+		self.next_u64() % n
+	}
+}
+
+/// Types whose values can be generated by the law checks.
+pub trait Generate {
+	/// Generates a new value.
+	fn generate(rng: &mut Rng) -> Self;
+}
+
+macro_rules! generate_integer {
+	($($ty:ty),*) => {
+		$(
+			impl Generate for $ty {
+				#[inline(always)]
+				fn generate(rng: &mut Rng) -> Self {
+					rng.below(DOMAIN) as $ty
+				}
+			}
+		)*
+	};
+}
+
+generate_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl Generate for bool {
+	#[inline(always)]
+	fn generate(rng: &mut Rng) -> Self {
+		rng.below(2) == 1
+	}
+}
+
+impl Generate for char {
+	#[inline(always)]
+	fn generate(rng: &mut Rng) -> Self {
+		(b'a' + rng.below(DOMAIN) as u8) as char
+	}
+}
+
+impl Generate for String {
+	#[inline(always)]
+	fn generate(rng: &mut Rng) -> Self {
+		alloc::format!("k{}", rng.below(DOMAIN))
+	}
+}
+
+#[cfg(feature = "serde_json")]
+impl Generate for serde_json::Value {
+	#[inline(always)]
+	fn generate(rng: &mut Rng) -> Self {
+		rng.below(DOMAIN).into()
+	}
+}
+
+#[cfg(feature = "ijson")]
+impl Generate for ijson::IValue {
+	#[inline(always)]
+	fn generate(rng: &mut Rng) -> Self {
+		rng.below(DOMAIN).into()
+	}
+}
+
+#[cfg(feature = "ijson")]
+impl Generate for ijson::IString {
+	#[inline(always)]
+	fn generate(rng: &mut Rng) -> Self {
+		String::generate(rng).into()
+	}
+}
+
+/// Position of an operation in the checked sequences.
+struct Step {
+	seed: u64,
+	index: usize,
+}
+
+impl fmt::Display for Step {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "seed {}, step {}", self.seed, self.index)
+	}
+}
+
+/// Runs `f` on every step of every checked sequence.
+fn run(mut f: impl FnMut(&mut Rng, Step)) {
+	for seed in 0..SEEDS {
+		let mut rng = Rng::new(seed);
+		for index in 0..STEPS {
+			f(&mut rng, Step { seed, index })
+		}
+	}
+}
+
+macro_rules! law {
+	($step:expr, $op:expr, $left:expr, $right:expr) => {
+		assert_eq!(
+			$left,
+			$right,
+			"`{}` disagrees with the reference model ({})",
+			$op,
+			$step
+		)
+	};
+}
+
+/// Checks that a stack behaves like a `Vec` used as a stack.
+///
+/// Drives the stack with [`PushBack`](crate::PushBack),
+/// [`PopBack`](crate::PopBack), [`Back`](crate::Back) and
+/// [`BackMut`](crate::BackMut), and checks that [`Len`](crate::Len)
+/// and [`Iter`] are consistent with the pushed items.
+///
+/// At most 200 items are ever in the stack at the same time.
+pub fn check_stack_mut<S, T>()
+where
+	S: Default + StackMut<T> + Iter,
+	T: Generate + Clone + PartialEq + fmt::Debug,
+{
+	let mut stack = S::default();
+	let mut model: Vec<T> = Vec::new();
+	run(|rng, step| {
+		if step.index == 0 {
+			stack = S::default();
+			model.clear();
+		}
+
+		match rng.below(5) {
+			0 | 1 => {
+				let item = T::generate(rng);
+				stack.push_back(item.clone());
+				model.push(item)
+			}
+			2 => law!(step, "pop_back", stack.pop_back(), model.pop()),
+			3 => law!(step, "back", stack.back().as_deref(), model.last()),
+			_ => {
+				let item = T::generate(rng);
+				match (stack.back_mut(), model.last_mut()) {
+					(Some(mut r), Some(m)) => {
+						*r = item.clone();
+						*m = item
+					}
+					(r, m) => law!(step, "back_mut", r.is_some(), m.is_some()),
+				}
+			}
+		}
+
+		law!(step, "len", stack.len(), model.len());
+		law!(step, "is_empty", stack.is_empty(), model.is_empty());
+		law!(step, "iter", Iter::iter(&stack).count(), model.len());
+		for (r, m) in Iter::iter(&stack).zip(&model) {
+			law!(step, "iter", &*r, m)
+		}
+	})
+}
+
+/// Checks that a set behaves like a set of distinct items.
+///
+/// Drives the set with [`Insert`](crate::Insert),
+/// [`Remove`](crate::Remove) and [`Get`](crate::Get), and checks that
+/// [`Len`](crate::Len) and [`Iter`] are consistent with the inserted items.
+pub fn check_set_mut<S, T>()
+where
+	S: Default + SetMut<T> + Iter,
+	T: Generate + Clone + PartialEq + fmt::Debug,
+{
+	let mut set = S::default();
+	let mut model: Vec<T> = Vec::new();
+	run(|rng, step| {
+		if step.index == 0 {
+			set = S::default();
+			model.clear();
+		}
+
+		let item = T::generate(rng);
+		let position = model.iter().position(|m| *m == item);
+		match rng.below(4) {
+			0 | 1 => {
+				law!(step, "insert", set.insert(item.clone()), position.is_none());
+				if position.is_none() {
+					model.push(item)
+				}
+			}
+			2 => law!(
+				step,
+				"remove",
+				set.remove(&item),
+				position.map(|i| model.swap_remove(i))
+			),
+			_ => {
+				law!(step, "get", set.get(&item).as_deref(), position.map(|i| &model[i]));
+				law!(step, "contains", set.contains(&item), position.is_some())
+			}
+		}
+
+		law!(step, "len", set.len(), model.len());
+		law!(step, "is_empty", set.is_empty(), model.is_empty());
+		law!(step, "iter", Iter::iter(&set).count(), model.len());
+		for r in Iter::iter(&set) {
+			law!(step, "iter", model.iter().any(|m| *m == *r), true)
+		}
+	})
+}
+
+/// Checks that a map behaves like a set of key-value pairs with distinct keys.
+///
+/// Drives the map with [`MapInsert`], [`Get`](crate::Get),
+/// [`GetMut`](crate::GetMut), [`GetKeyValue`](crate::GetKeyValue),
+/// [`Remove`](crate::Remove) and [`RemoveEntry`](crate::RemoveEntry),
+/// and checks that [`Len`](crate::Len) and [`MapIter`] are consistent
+/// with the inserted pairs.
+pub fn check_map_mut<M, K, V>()
+where
+	M: Default + MapMut<K, V> + MapIter,
+	K: Generate + Clone + PartialEq + fmt::Debug,
+	V: Generate + Clone + PartialEq + fmt::Debug,
+{
+	let mut map = M::default();
+	let mut model: Vec<(K, V)> = Vec::new();
+	run(|rng, step| {
+		if step.index == 0 {
+			map = M::default();
+			model.clear();
+		}
+
+		let key = K::generate(rng);
+		let position = model.iter().position(|(k, _)| *k == key);
+		match rng.below(7) {
+			0 | 1 => {
+				let value = V::generate(rng);
+				let expected = match position {
+					Some(i) => Some(core::mem::replace(&mut model[i].1, value.clone())),
+					None => {
+						model.push((key.clone(), value.clone()));
+						None
+					}
+				};
+				law!(step, "insert", MapInsert::insert(&mut map, key, value), expected)
+			}
+			2 => {
+				let expected = position.map(|i| &model[i].1);
+				law!(step, "get", map.get(&key).as_deref(), expected);
+				law!(step, "contains", map.contains(&key), position.is_some())
+			}
+			3 => law!(
+				step,
+				"get_key_value",
+				map.get_key_value(&key).map(|(k, v)| ((*k).clone(), (*v).clone())),
+				position.map(|i| model[i].clone())
+			),
+			4 => {
+				let value = V::generate(rng);
+				match (map.get_mut(&key), position) {
+					(Some(mut r), Some(i)) => {
+						*r = value.clone();
+						model[i].1 = value
+					}
+					(r, position) => law!(step, "get_mut", r.is_some(), position.is_some()),
+				}
+			}
+			5 => law!(
+				step,
+				"remove",
+				map.remove(&key),
+				position.map(|i| model.swap_remove(i).1)
+			),
+			_ => law!(
+				step,
+				"remove_entry",
+				map.remove_entry(&key),
+				position.map(|i| model.swap_remove(i))
+			),
+		}
+
+		law!(step, "len", map.len(), model.len());
+		law!(step, "is_empty", map.is_empty(), model.is_empty());
+		law!(step, "iter", MapIter::iter(&map).count(), model.len());
+		for (k, v) in MapIter::iter(&map) {
+			let entry = model.iter().find(|(mk, _)| *mk == *k).map(|(_, mv)| mv);
+			law!(step, "iter", entry, Some(&*v))
+		}
+	})
+}
+
+/// Checks that a slab behaves like a map from the keys it allocates.
+///
+/// Drives the slab with [`Insert`](crate::Insert), [`Get`](crate::Get),
+/// [`GetMut`](crate::GetMut) and [`Remove`](crate::Remove), and checks
+/// that keys returned by `insert` are not already in use, and that
+/// [`Len`](crate::Len) is consistent with the inserted items.
+pub fn check_slab_mut<S, T>()
+where
+	S: Default + SlabMut<T>,
+	T: Generate + Clone + PartialEq + fmt::Debug,
+{
+	let mut slab = S::default();
+	let mut model: Vec<(usize, T)> = Vec::new();
+	run(|rng, step| {
+		if step.index == 0 {
+			slab = S::default();
+			model.clear();
+		}
+
+		let key = if !model.is_empty() && rng.below(4) != 0 {
+			model[rng.below(model.len() as u64) as usize].0
+		} else {
+			rng.below(DOMAIN) as usize
+		};
+		let position = model.iter().position(|(k, _)| *k == key);
+		match rng.below(5) {
+			0 | 1 => {
+				let item = T::generate(rng);
+				let key = slab.insert(item.clone());
+				let in_use = model.iter().any(|(k, _)| *k == key);
+				law!(step, "insert", in_use, false);
+				model.push((key, item))
+			}
+			2 => {
+				law!(step, "get", slab.get(key).as_deref(), position.map(|i| &model[i].1));
+				law!(step, "contains", slab.contains(key), position.is_some())
+			}
+			3 => {
+				let item = T::generate(rng);
+				match (slab.get_mut(key), position) {
+					(Some(mut r), Some(i)) => {
+						*r = item.clone();
+						model[i].1 = item
+					}
+					(r, position) => law!(step, "get_mut", r.is_some(), position.is_some()),
+				}
+			}
+			_ => law!(
+				step,
+				"remove",
+				slab.remove(key),
+				position.map(|i| model.swap_remove(i).1)
+			),
+		}
+
+		law!(step, "len", slab.len(), model.len());
+		law!(step, "is_empty", slab.is_empty(), model.is_empty());
+	})
+}
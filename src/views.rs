@@ -0,0 +1,560 @@
+//! Lazy read-only views over collections.
+//!
+//! A view wraps a collection (or a reference to a collection) and exposes
+//! a transformed version of it through the traits of this crate, without
+//! copying the collection.
+//! Items are borrowed from the underlying collection, except for the
+//! [`Mapped`] view which computes them on access.
+//!
+//! ```
+//! use cc_traits::{views::KeysView, Set};
+//!
+//! fn is_registered<S: Set<String>>(names: &S, name: &String) -> bool {
+//!     names.contains(name)
+//! }
+//!
+//! #[cfg(feature = "std")]
+//! {
+//!     let mut ages = std::collections::HashMap::new();
+//!     ages.insert("alice".to_string(), 42);
+//!     assert!(is_registered(&KeysView::new(&ages), &"alice".to_string()));
+//! }
+//! ```
+use crate::{
+	Collection, CollectionRef, Get, GetKeyValue, Iter, Keyed, KeyedRef, Keys, Len,
+	SimpleCollectionRef, SimpleKeyedRef, Values,
+};
+use core::ops::Deref;
+
+/// View projecting each item of a collection to one of its fields.
+///
+/// The projection function `F` maps a reference to an item of `C` to a
+/// reference to the projected item.
+pub struct Projected<C, F> {
+	collection: C,
+	f: F,
+}
+
+impl<C: Collection, F> Projected<C, F> {
+	/// Creates a new view projecting the items of `collection` with `f`.
+	pub fn new<T>(collection: C, f: F) -> Self
+	where
+		F: Fn(&C::Item) -> &T,
+	{
+		Self { collection, f }
+	}
+}
+
+impl<C, F> Projected<C, F> {
+	/// Returns the underlying collection.
+	pub fn into_inner(self) -> C {
+		self.collection
+	}
+}
+
+impl<C: Collection, F, T> Collection for Projected<C, F>
+where
+	F: Fn(&C::Item) -> &T,
+{
+	type Item = T;
+}
+
+impl<C: CollectionRef, F, T> CollectionRef for Projected<C, F>
+where
+	F: Fn(&C::Item) -> &T,
+{
+	type ItemRef<'a> = ProjectedRef<'a, C, F> where Self: 'a;
+
+	#[inline(always)]
+	fn upcast_item_ref<'short, 'long: 'short>(r: Self::ItemRef<'long>) -> Self::ItemRef<'short>
+	where
+		Self: 'long,
+	{
+		ProjectedRef {
+			r: C::upcast_item_ref(r.r),
+			f: r.f,
+		}
+	}
+}
+
+impl<C: SimpleCollectionRef, F, T> SimpleCollectionRef for Projected<C, F>
+where
+	F: Fn(&C::Item) -> &T,
+{
+	#[inline(always)]
+	fn into_ref<'r>(r: Self::ItemRef<'r>) -> &'r T
+	where
+		Self: 'r,
+	{
+		(r.f)(C::into_ref(r.r))
+	}
+}
+
+impl<C: Len, F> Len for Projected<C, F> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.collection.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.collection.is_empty()
+	}
+}
+
+impl<C: Get<Q>, F, T, Q> Get<Q> for Projected<C, F>
+where
+	F: Fn(&C::Item) -> &T,
+{
+	#[inline(always)]
+	fn get(&self, key: Q) -> Option<Self::ItemRef<'_>> {
+		self.collection.get(key).map(|r| ProjectedRef { r, f: &self.f })
+	}
+
+	#[inline(always)]
+	fn contains(&self, key: Q) -> bool {
+		self.collection.contains(key)
+	}
+}
+
+impl<C: Iter, F, T> Iter for Projected<C, F>
+where
+	F: Fn(&C::Item) -> &T,
+{
+	type Iter<'a> = ProjectedIter<'a, C, F> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		ProjectedIter {
+			iter: self.collection.iter(),
+			f: &self.f,
+		}
+	}
+}
+
+/// Reference to an item of a [`Projected`] view.
+///
+/// Dereferences to the projected item.
+pub struct ProjectedRef<'a, C: CollectionRef + 'a, F> {
+	r: C::ItemRef<'a>,
+	f: &'a F,
+}
+
+impl<'a, C: CollectionRef + 'a, F> Clone for ProjectedRef<'a, C, F> {
+	#[inline(always)]
+	fn clone(&self) -> Self {
+		Self {
+			r: self.r.clone(),
+			f: self.f,
+		}
+	}
+}
+
+impl<'a, C: CollectionRef + 'a, F, T> Deref for ProjectedRef<'a, C, F>
+where
+	F: Fn(&C::Item) -> &T,
+{
+	type Target = T;
+
+	#[inline(always)]
+	fn deref(&self) -> &T {
+		(self.f)(&self.r)
+	}
+}
+
+/// Iterator over the items of a [`Projected`] view.
+pub struct ProjectedIter<'a, C: Iter + 'a, F> {
+	iter: C::Iter<'a>,
+	f: &'a F,
+}
+
+impl<'a, C: Iter + 'a, F> Iterator for ProjectedIter<'a, C, F> {
+	type Item = ProjectedRef<'a, C, F>;
+
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item> {
+		let f = self.f;
+		self.iter.next().map(|r| ProjectedRef { r, f })
+	}
+
+	#[inline(always)]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.iter.size_hint()
+	}
+}
+
+/// View mapping each item of a collection to an owned value.
+///
+/// Unlike [`Projected`], the mapping function `F` returns the new item by
+/// value. It is called each time an item is accessed, and the returned
+/// [`MappedRef`] owns the mapped item.
+pub struct Mapped<C, F> {
+	collection: C,
+	f: F,
+}
+
+impl<C: Collection, F> Mapped<C, F> {
+	/// Creates a new view mapping the items of `collection` with `f`.
+	pub fn new<T>(collection: C, f: F) -> Self
+	where
+		F: Fn(&C::Item) -> T,
+	{
+		Self { collection, f }
+	}
+}
+
+impl<C, F> Mapped<C, F> {
+	/// Returns the underlying collection.
+	pub fn into_inner(self) -> C {
+		self.collection
+	}
+}
+
+impl<C: Collection, F, T> Collection for Mapped<C, F>
+where
+	F: Fn(&C::Item) -> T,
+{
+	type Item = T;
+}
+
+impl<C: Collection, F, T: Clone> CollectionRef for Mapped<C, F>
+where
+	F: Fn(&C::Item) -> T,
+{
+	type ItemRef<'a> = MappedRef<T> where Self: 'a;
+
+	#[inline(always)]
+	fn upcast_item_ref<'short, 'long: 'short>(r: Self::ItemRef<'long>) -> Self::ItemRef<'short>
+	where
+		Self: 'long,
+	{
+		r
+	}
+}
+
+impl<C: Len, F> Len for Mapped<C, F> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.collection.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.collection.is_empty()
+	}
+}
+
+impl<C: Get<Q>, F, T: Clone, Q> Get<Q> for Mapped<C, F>
+where
+	F: Fn(&C::Item) -> T,
+{
+	#[inline(always)]
+	fn get(&self, key: Q) -> Option<Self::ItemRef<'_>> {
+		self.collection.get(key).map(|r| MappedRef((self.f)(&r)))
+	}
+
+	#[inline(always)]
+	fn contains(&self, key: Q) -> bool {
+		self.collection.contains(key)
+	}
+}
+
+impl<C: Iter, F, T: Clone> Iter for Mapped<C, F>
+where
+	F: Fn(&C::Item) -> T,
+{
+	type Iter<'a> = MappedIter<'a, C, F> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		MappedIter {
+			iter: self.collection.iter(),
+			f: &self.f,
+		}
+	}
+}
+
+/// Item of a [`Mapped`] view.
+///
+/// Owns the mapped item, and dereferences to it.
+#[derive(Clone)]
+pub struct MappedRef<T>(T);
+
+impl<T> MappedRef<T> {
+	/// Returns the mapped item.
+	pub fn into_inner(self) -> T {
+		self.0
+	}
+}
+
+impl<T> Deref for MappedRef<T> {
+	type Target = T;
+
+	#[inline(always)]
+	fn deref(&self) -> &T {
+		&self.0
+	}
+}
+
+/// Iterator over the items of a [`Mapped`] view.
+pub struct MappedIter<'a, C: Iter + 'a, F> {
+	iter: C::Iter<'a>,
+	f: &'a F,
+}
+
+impl<'a, C: Iter + 'a, F, T> Iterator for MappedIter<'a, C, F>
+where
+	F: Fn(&C::Item) -> T,
+{
+	type Item = MappedRef<T>;
+
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item> {
+		let f = self.f;
+		self.iter.next().map(|r| MappedRef(f(&r)))
+	}
+
+	#[inline(always)]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.iter.size_hint()
+	}
+}
+
+/// View over the keys of a map, usable as a set.
+pub struct KeysView<M>(M);
+
+impl<M> KeysView<M> {
+	/// Creates a new view over the keys of `map`.
+	pub fn new(map: M) -> Self {
+		Self(map)
+	}
+
+	/// Returns the underlying map.
+	pub fn into_inner(self) -> M {
+		self.0
+	}
+}
+
+impl<M: Keyed> Collection for KeysView<M> {
+	type Item = M::Key;
+}
+
+impl<M: KeyedRef> CollectionRef for KeysView<M> {
+	type ItemRef<'a> = M::KeyRef<'a> where Self: 'a;
+
+	#[inline(always)]
+	fn upcast_item_ref<'short, 'long: 'short>(r: Self::ItemRef<'long>) -> Self::ItemRef<'short>
+	where
+		Self: 'long,
+	{
+		M::upcast_key_ref(r)
+	}
+}
+
+impl<M: SimpleKeyedRef> SimpleCollectionRef for KeysView<M> {
+	#[inline(always)]
+	fn into_ref<'r>(r: Self::ItemRef<'r>) -> &'r M::Key
+	where
+		Self: 'r,
+	{
+		M::into_ref(r)
+	}
+}
+
+impl<M: Len> Len for KeysView<M> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+}
+
+impl<M: GetKeyValue<Q>, Q> Get<Q> for KeysView<M> {
+	#[inline(always)]
+	fn get(&self, key: Q) -> Option<Self::ItemRef<'_>> {
+		self.0.get_key_value(key).map(|(k, _)| k)
+	}
+}
+
+impl<M: Keys> Iter for KeysView<M> {
+	type Iter<'a> = M::Keys<'a> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.0.keys()
+	}
+}
+
+/// View over the values of a map.
+///
+/// Values can be queried by their key.
+pub struct ValuesView<M>(M);
+
+impl<M> ValuesView<M> {
+	/// Creates a new view over the values of `map`.
+	pub fn new(map: M) -> Self {
+		Self(map)
+	}
+
+	/// Returns the underlying map.
+	pub fn into_inner(self) -> M {
+		self.0
+	}
+}
+
+impl<M: Collection> Collection for ValuesView<M> {
+	type Item = M::Item;
+}
+
+impl<M: CollectionRef> CollectionRef for ValuesView<M> {
+	type ItemRef<'a> = M::ItemRef<'a> where Self: 'a;
+
+	#[inline(always)]
+	fn upcast_item_ref<'short, 'long: 'short>(r: Self::ItemRef<'long>) -> Self::ItemRef<'short>
+	where
+		Self: 'long,
+	{
+		M::upcast_item_ref(r)
+	}
+}
+
+impl<M: SimpleCollectionRef> SimpleCollectionRef for ValuesView<M> {
+	#[inline(always)]
+	fn into_ref<'r>(r: Self::ItemRef<'r>) -> &'r M::Item
+	where
+		Self: 'r,
+	{
+		M::into_ref(r)
+	}
+}
+
+impl<M: Len> Len for ValuesView<M> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+}
+
+impl<M: Get<Q>, Q> Get<Q> for ValuesView<M> {
+	#[inline(always)]
+	fn get(&self, key: Q) -> Option<Self::ItemRef<'_>> {
+		self.0.get(key)
+	}
+
+	#[inline(always)]
+	fn contains(&self, key: Q) -> bool {
+		self.0.contains(key)
+	}
+}
+
+impl<M: Values> Iter for ValuesView<M> {
+	type Iter<'a> = M::Values<'a> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.0.values()
+	}
+}
+
+/// View over the items of a collection satisfying a predicate.
+///
+/// Since the number of matching items is not known without iterating,
+/// this view only implements [`Iter`].
+pub struct Filtered<C, P> {
+	collection: C,
+	predicate: P,
+}
+
+impl<C: Collection, P> Filtered<C, P> {
+	/// Creates a new view over the items of `collection` satisfying `predicate`.
+	pub fn new(collection: C, predicate: P) -> Self
+	where
+		P: Fn(&C::Item) -> bool,
+	{
+		Self {
+			collection,
+			predicate,
+		}
+	}
+}
+
+impl<C, P> Filtered<C, P> {
+	/// Returns the underlying collection.
+	pub fn into_inner(self) -> C {
+		self.collection
+	}
+}
+
+impl<C: Collection, P> Collection for Filtered<C, P> {
+	type Item = C::Item;
+}
+
+impl<C: CollectionRef, P> CollectionRef for Filtered<C, P> {
+	type ItemRef<'a> = C::ItemRef<'a> where Self: 'a;
+
+	#[inline(always)]
+	fn upcast_item_ref<'short, 'long: 'short>(r: Self::ItemRef<'long>) -> Self::ItemRef<'short>
+	where
+		Self: 'long,
+	{
+		C::upcast_item_ref(r)
+	}
+}
+
+impl<C: SimpleCollectionRef, P> SimpleCollectionRef for Filtered<C, P> {
+	#[inline(always)]
+	fn into_ref<'r>(r: Self::ItemRef<'r>) -> &'r C::Item
+	where
+		Self: 'r,
+	{
+		C::into_ref(r)
+	}
+}
+
+impl<C: Iter, P> Iter for Filtered<C, P>
+where
+	P: Fn(&C::Item) -> bool,
+{
+	type Iter<'a> = FilteredIter<'a, C, P> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		FilteredIter {
+			iter: self.collection.iter(),
+			predicate: &self.predicate,
+		}
+	}
+}
+
+/// Iterator over the items of a [`Filtered`] view.
+pub struct FilteredIter<'a, C: Iter + 'a, P> {
+	iter: C::Iter<'a>,
+	predicate: &'a P,
+}
+
+impl<'a, C: Iter + 'a, P> Iterator for FilteredIter<'a, C, P>
+where
+	P: Fn(&C::Item) -> bool,
+{
+	type Item = C::ItemRef<'a>;
+
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item> {
+		let predicate = self.predicate;
+		self.iter.find(|r| predicate(r))
+	}
+
+	#[inline(always)]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0, self.iter.size_hint().1)
+	}
+}
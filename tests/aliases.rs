@@ -0,0 +1,54 @@
+#![cfg(feature = "alloc")]
+use cc_traits::{
+	Deque, DequeMut, Iter, IterMut, Remove, Stack, StackMut, Vec, VecDeque, VecDequeMut, VecMut,
+};
+
+fn assert_stack<T, C: Stack<T>>() {}
+fn assert_stack_mut<T, C: StackMut<T>>() {}
+fn assert_vec<T, C: Vec<T>>() {}
+fn assert_vec_mut<T, C: VecMut<T>>() {}
+fn assert_deque<T, C: Deque<T>>() {}
+fn assert_deque_mut<T, C: DequeMut<T>>() {}
+fn assert_vec_deque<T, C: VecDeque<T>>() {}
+fn assert_vec_deque_mut<T, C: VecDequeMut<T>>() {}
+
+#[test]
+fn vec_deque_aliases() {
+	type D = std::collections::VecDeque<u32>;
+	assert_stack::<u32, D>();
+	assert_stack_mut::<u32, D>();
+	assert_vec::<u32, D>();
+	assert_vec_mut::<u32, D>();
+	assert_deque::<u32, D>();
+	assert_deque_mut::<u32, D>();
+	assert_vec_deque::<u32, D>();
+	assert_vec_deque_mut::<u32, D>();
+}
+
+fn work_queue<Q: Deque<u32> + DequeMut<u32> + Default>() -> Q {
+	let mut queue = Q::default();
+	queue.push_back(2);
+	queue.push_back(3);
+	queue.push_front(1);
+	assert_eq!(queue.len(), 3);
+	assert_eq!(queue.front().map(|t| *t), Some(1));
+	assert_eq!(queue.back().map(|t| *t), Some(3));
+	*queue.front_mut().unwrap() = 0;
+	assert_eq!(queue.pop_front(), Some(0));
+	assert_eq!(queue.pop_back(), Some(3));
+	queue
+}
+
+#[test]
+fn vec_deque_operations() {
+	let mut queue: std::collections::VecDeque<u32> = work_queue();
+	queue.push_back(4);
+	assert_eq!(Iter::iter(&queue).copied().collect::<std::vec::Vec<_>>(), [2, 4]);
+	for t in IterMut::iter_mut(&mut queue) {
+		*t *= 10
+	}
+	assert_eq!(queue[1], 40);
+	assert_eq!(Remove::remove(&mut queue, 2), None);
+	assert_eq!(Remove::remove(&mut queue, 0), Some(20));
+	assert_eq!(queue.len(), 1);
+}
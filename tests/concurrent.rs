@@ -0,0 +1,122 @@
+#![cfg(feature = "std")]
+use cc_traits::concurrent::{
+	ConcurrentGet, ConcurrentInsert, ConcurrentMapInsert, ConcurrentPop, ConcurrentPush,
+	ConcurrentRemove,
+};
+use std::{
+	collections::{BTreeSet, HashMap, VecDeque},
+	sync::{Mutex, RwLock},
+	thread,
+};
+
+/// Inserts `0..n` from 4 threads, and checks every key got inserted once.
+fn fill_map<M>(map: &M, n: usize)
+where
+	M: Sync + ConcurrentMapInsert<usize, Item = usize, Output = Option<usize>>,
+	M: for<'a> ConcurrentGet<&'a usize>,
+{
+	thread::scope(|s| {
+		for t in 0..4 {
+			s.spawn(move || {
+				for i in (t..n).step_by(4) {
+					assert_eq!(map.insert(i, i * 2), None)
+				}
+			});
+		}
+	});
+
+	for i in 0..n {
+		assert_eq!(map.get(&i).as_deref(), Some(&(i * 2)))
+	}
+	assert!(!map.contains(&n))
+}
+
+/// Pushes `0..n` from 4 threads, then pops every item from 4 threads.
+fn drain_queue<Q>(queue: &Q, n: usize)
+where
+	Q: Sync + ConcurrentPush + ConcurrentPop<Item = usize>,
+{
+	thread::scope(|s| {
+		for t in 0..4 {
+			s.spawn(move || {
+				for i in (t..n).step_by(4) {
+					queue.push(i);
+				}
+			});
+		}
+	});
+
+	let popped = Mutex::new(BTreeSet::new());
+	thread::scope(|s| {
+		for _ in 0..4 {
+			s.spawn(|| {
+				while let Some(i) = queue.pop() {
+					assert!(popped.insert(i))
+				}
+			});
+		}
+	});
+
+	assert_eq!(popped.into_inner().unwrap(), (0..n).collect())
+}
+
+fn check_set<S>(set: &S)
+where
+	S: ConcurrentInsert<Item = String, Output = bool>,
+	S: for<'a> ConcurrentGet<&'a str> + for<'a> ConcurrentRemove<&'a str>,
+{
+	assert!(set.insert("a".to_string()));
+	assert!(!set.insert("a".to_string()));
+	assert_eq!(set.get("a").as_deref().map(String::as_str), Some("a"));
+	assert_eq!(set.remove("a").as_deref(), Some("a"));
+	assert!(!set.contains("a"))
+}
+
+#[test]
+fn locked_maps() {
+	fill_map(&Mutex::new(HashMap::new()), 100);
+	fill_map(&RwLock::new(HashMap::new()), 100);
+
+	let map = RwLock::new(HashMap::from([("a".to_string(), 1)]));
+	let a = map.get("a").unwrap();
+	let also_a = map.get("a").unwrap();
+	assert_eq!(*a + *also_a, 2);
+	drop((a, also_a));
+	assert_eq!(map.remove("a"), Some(1));
+	assert!(map.get("a").is_none())
+}
+
+#[test]
+fn locked_queues() {
+	drain_queue(&Mutex::new(VecDeque::new()), 100);
+	drain_queue(&RwLock::new(VecDeque::new()), 100);
+
+	let queue = Mutex::new(VecDeque::new());
+	queue.push(1);
+	queue.push(2);
+	assert_eq!(queue.pop(), Some(1))
+}
+
+#[test]
+fn locked_sets() {
+	check_set(&Mutex::new(BTreeSet::new()));
+	check_set(&RwLock::new(BTreeSet::new()))
+}
+
+#[cfg(feature = "dashmap")]
+#[test]
+fn dashmap() {
+	fill_map(&dashmap::DashMap::new(), 1000);
+	check_set(&dashmap::DashSet::new())
+}
+
+#[cfg(feature = "crossbeam-queue")]
+#[test]
+fn crossbeam_queue() {
+	drain_queue(&crossbeam_queue::SegQueue::new(), 1000);
+
+	let queue = crossbeam_queue::ArrayQueue::new(1);
+	assert!(ConcurrentPush::push(&queue, 1).is_ok());
+	assert_eq!(ConcurrentPush::push(&queue, 2).unwrap_err().into_element(), 2);
+	assert_eq!(ConcurrentPop::pop(&queue), Some(1))
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "alloc")]
+use cc_traits::{AsSlices, AsSlicesMut, Contiguous, ContiguousMut, VecMut};
+use std::collections::VecDeque;
+
+fn scale<V>(v: &mut V, factor: f32) -> f32
+where
+	V: VecMut<f32> + ContiguousMut,
+{
+	v.push_back(4.0);
+	for x in v.as_mut_slice() {
+		*x *= factor
+	}
+
+	v.as_slice().iter().sum()
+}
+
+#[test]
+fn vec() {
+	let mut v = vec![1.0, 2.0, 3.0];
+	assert_eq!(scale(&mut v, 2.0), 20.0);
+	assert_eq!(v, [2.0, 4.0, 6.0, 8.0]);
+}
+
+#[test]
+fn slices() {
+	fn first<C: Contiguous<Item = u8> + ?Sized>(c: &C) -> Option<&u8> {
+		c.as_slice().first()
+	}
+
+	assert_eq!(first(&[1u8, 2, 3]), Some(&1));
+	assert_eq!(first(&[1u8, 2, 3][1..]), Some(&2));
+	assert_eq!(first(&Box::new(vec![3u8])), Some(&3));
+}
+
+fn joined<D>(d: &mut D) -> Vec<u32>
+where
+	D: AsSlicesMut<Item = u32>,
+{
+	{
+		let (a, b) = d.as_mut_slices();
+		for x in a.iter_mut().chain(b) {
+			*x += 1
+		}
+	}
+
+	let (a, b) = d.as_slices();
+	a.iter().chain(b).copied().collect()
+}
+
+#[test]
+fn vec_deque() {
+	let mut d = VecDeque::new();
+	d.push_back(2);
+	d.push_back(3);
+	d.push_front(1);
+	assert_eq!(joined(&mut d), [2, 3, 4]);
+	assert_eq!(joined(&mut &mut d), [3, 4, 5]);
+}
+
+#[cfg(feature = "smallvec")]
+#[test]
+fn smallvec() {
+	let mut v: smallvec::SmallVec<[f32; 4]> = smallvec::smallvec![1.0];
+	assert_eq!(scale(&mut v, 3.0), 15.0);
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn heapless() {
+	let mut v: heapless::Vec<u8, 4> = heapless::Vec::new();
+	v.extend([1, 2]);
+	assert_eq!(Contiguous::as_slice(&v), [1, 2]);
+
+	let mut d: heapless::Deque<u32, 4> = heapless::Deque::new();
+	let _ = d.push_back(1);
+	let _ = d.push_front(0);
+	assert_eq!(joined(&mut d), [1, 2]);
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "alloc")]
+use cc_traits::{
+	Cursor, CursorApi, CursorMut, CursorMutApi, DequeMut, IntoItems, Retain,
+};
+use std::collections::{LinkedList, VecDeque};
+
+fn deque<D: Default + DequeMut<u32>>() -> D {
+	let mut deque = D::default();
+	deque.push_back(2);
+	deque.push_front(1);
+	deque.push_back(3);
+	*deque.back_mut().unwrap() += 1;
+	assert_eq!(deque.len(), 3);
+	assert_eq!(deque.pop_front(), Some(1));
+	deque
+}
+
+/// Reads the sequence backward, wrapping around the ghost non-item.
+fn read<S>(sequence: &S) -> Vec<u32>
+where
+	S: CursorApi<Item = u32>,
+{
+	let mut cursor = sequence.cursor_back();
+	let mut result = Vec::new();
+	while let Some(t) = cursor.current() {
+		result.push(*t);
+		cursor.move_prev()
+	}
+
+	assert_eq!(cursor.index(), None);
+	cursor.move_prev();
+	assert_eq!(cursor.current().map(|t| *t), result.first().copied());
+	result
+}
+
+/// Edits the sequence `[1, 2, 3, 4, 5]` from its front.
+fn edit_buffer<'a, C: CursorMut<'a, Item = u32>>(mut cursor: C) -> C::Split {
+	assert_eq!(cursor.index(), Some(0));
+	cursor.move_next();
+	*cursor.current().unwrap() *= 10;
+	cursor.insert_before(15);
+	cursor.insert_after(25);
+	assert_eq!(cursor.index(), Some(2));
+	cursor.move_next();
+	cursor.move_next();
+	assert_eq!(cursor.remove_current(), Some(3));
+	assert_eq!(cursor.current().copied(), Some(4));
+
+	cursor.move_next();
+	cursor.move_next();
+	assert_eq!(cursor.index(), None);
+	assert_eq!(cursor.remove_current(), None);
+	cursor.move_next();
+	assert_eq!(cursor.current().copied(), Some(1));
+	cursor.move_next();
+	cursor.move_next();
+	cursor.move_next();
+	cursor.split_after()
+}
+
+#[test]
+fn linked_list() {
+	let list: LinkedList<u32> = deque();
+	assert_eq!(list.into_items().collect::<Vec<_>>(), [2, 4]);
+
+	let mut list: LinkedList<u32> = (1..=5).collect();
+	assert_eq!(read(&list), [5, 4, 3, 2, 1]);
+
+	let split = edit_buffer(CursorMutApi::cursor_front_mut(&mut list));
+	assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 15, 20, 25]);
+	assert_eq!(split.into_iter().collect::<Vec<_>>(), [4, 5]);
+
+	Retain::retain(&mut list, |t| t % 2 == 1);
+	assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 15, 25]);
+}
+
+#[test]
+fn vec_deque() {
+	let mut deque: VecDeque<u32> = (1..=5).collect();
+	assert_eq!(read(&deque), [5, 4, 3, 2, 1]);
+
+	let split = edit_buffer(CursorMutApi::cursor_front_mut(&mut deque));
+	assert_eq!(deque, [1, 15, 20, 25]);
+	assert_eq!(split, [4, 5]);
+}
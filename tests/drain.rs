@@ -0,0 +1,167 @@
+#![cfg(feature = "alloc")]
+use cc_traits::{
+	Collection, Drain, DrainRange, IntoItems, Len, MapDrain, MapIntoItems, MapMut, MapRetain,
+	PushBack, Retain,
+};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+fn sequence<S>()
+where
+	S: Default + Drain + DrainRange<std::ops::Range<usize>> + Retain + IntoItems,
+	S: PushBack + Collection<Item = u32> + Len,
+{
+	let mut seq = S::default();
+	for t in 0..10 {
+		seq.push_back(t);
+	}
+
+	let drained: Vec<u32> = seq.drain_range(2..5).collect();
+	assert_eq!(drained, [2, 3, 4]);
+	assert_eq!(seq.len(), 7);
+
+	seq.retain(|t| t % 2 == 1);
+	let remaining: Vec<u32> = seq.into_items().collect();
+	assert_eq!(remaining, [1, 5, 7, 9]);
+
+	let mut seq = S::default();
+	seq.push_back(1);
+	seq.push_back(2);
+	let drained: Vec<u32> = seq.drain().collect();
+	assert_eq!(drained, [1, 2]);
+	assert!(seq.is_empty());
+}
+
+fn map<M>()
+where
+	M: Default + MapMut<u32, u32> + MapDrain + MapRetain + MapIntoItems + Drain + IntoItems,
+{
+	let mut map = M::default();
+	for k in 0..6 {
+		map.insert(k, k * 10);
+	}
+
+	MapRetain::retain(&mut map, |k, v| {
+		*v += 1;
+		*k < 4
+	});
+	assert_eq!(map.len(), 4);
+
+	let mut pairs: Vec<(u32, u32)> = MapIntoItems::into_items(map).collect();
+	pairs.sort();
+	assert_eq!(pairs, [(0, 1), (1, 11), (2, 21), (3, 31)]);
+
+	let mut map = M::default();
+	map.insert(1, 2);
+	map.insert(3, 4);
+	let mut values: Vec<u32> = Drain::drain(&mut map).collect();
+	values.sort();
+	assert_eq!(values, [2, 4]);
+	assert!(map.is_empty());
+}
+
+#[test]
+fn vec() {
+	sequence::<Vec<u32>>()
+}
+
+#[test]
+fn vec_deque() {
+	sequence::<VecDeque<u32>>()
+}
+
+#[test]
+fn btree_set() {
+	let mut set: BTreeSet<u32> = (0..5).collect();
+	set.retain(|t| *t > 1);
+	let items: Vec<u32> = Drain::drain(&mut set).collect();
+	assert_eq!(items, [2, 3, 4]);
+	assert!(set.is_empty());
+}
+
+#[test]
+fn btree_map() {
+	map::<BTreeMap<u32, u32>>()
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hash_map() {
+	map::<std::collections::HashMap<u32, u32>>()
+}
+
+#[cfg(feature = "ijson")]
+#[test]
+#[should_panic(expected = "attempted to drain up to maximum usize")]
+fn ijson_drain_range_overflow() {
+	let mut array = ijson::IArray::new();
+	array.push(1);
+	let _ = array.drain_range(0..=usize::MAX);
+}
+
+#[cfg(feature = "ijson")]
+fn ivalues(items: &[u32]) -> Vec<ijson::IValue> {
+	items.iter().map(|t| (*t).into()).collect()
+}
+
+#[cfg(feature = "ijson")]
+#[test]
+fn ijson_drain_range() {
+	use ijson::{IArray, IValue};
+
+	for range in [3..6, 4..4, 0..10, 0..0, 10..10] {
+		let mut vec: Vec<u32> = (0..10).collect();
+		let mut array: IArray = (0..10u32).collect();
+		let expected: Vec<u32> = vec.drain(range.clone()).collect();
+		let drained: Vec<IValue> = array.drain_range(range).collect();
+		assert_eq!(drained, ivalues(&expected));
+		assert_eq!(array.as_slice(), ivalues(&vec));
+	}
+
+	let mut array: IArray = (0..10u32).collect();
+	let drained: Vec<IValue> = array.drain_range(..).collect();
+	assert_eq!(drained, ivalues(&(0..10).collect::<Vec<_>>()));
+	assert!(array.is_empty());
+
+	let mut array: IArray = (0..10u32).collect();
+	let drained: Vec<IValue> = array.drain_range(7..=9).collect();
+	assert_eq!(drained, ivalues(&[7, 8, 9]));
+	assert_eq!(array.as_slice(), ivalues(&[0, 1, 2, 3, 4, 5, 6]));
+}
+
+#[cfg(feature = "ijson")]
+#[test]
+fn ijson_retain() {
+	let items = [5, 3, 8, 6, 1, 9, 2];
+	let keep = |t: u64| t % 3 != 0;
+
+	let mut vec = items.to_vec();
+	vec.retain(|t| keep(*t as u64));
+
+	let mut array: ijson::IArray = items.iter().copied().collect();
+	Retain::retain(&mut array, |v| keep(v.to_u64().unwrap()));
+	assert_eq!(array.as_slice(), ivalues(&vec));
+	assert_eq!(array.as_slice(), ivalues(&[5, 8, 1, 2]));
+
+	Retain::retain(&mut array, |_| false);
+	assert!(array.is_empty());
+}
+
+#[cfg(feature = "slab")]
+#[test]
+fn slab() {
+	use cc_traits::ExtendItems;
+
+	let mut slab = slab::Slab::new();
+	slab.extend_items([1u32, 2, 3, 4, 5]);
+	assert_eq!(slab.len(), 5);
+
+	Retain::retain(&mut slab, |t| t % 2 == 1);
+	let mut items: Vec<u32> = IntoItems::into_items(slab.clone()).collect();
+	items.sort_unstable();
+	assert_eq!(items, [1, 3, 5]);
+
+	let mut drained: Vec<u32> = Drain::drain(&mut slab).collect();
+	drained.sort_unstable();
+	assert_eq!(drained, [1, 3, 5]);
+	assert!(slab.is_empty());
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "std")]
+use cc_traits::{
+	dynamic::{BoxedMap, DynMapMut, DynSetMut, DynStackMut},
+	MapMut,
+};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList, VecDeque};
+
+fn stacks() -> Vec<Box<dyn DynStackMut<u32>>> {
+	vec![
+		Box::<Vec<u32>>::default(),
+		Box::<VecDeque<u32>>::default(),
+		Box::<LinkedList<u32>>::default(),
+	]
+}
+
+#[test]
+fn stack_objects() {
+	for mut stack in stacks() {
+		stack.push_back(1);
+		stack.push_back(2);
+		*stack.back_mut().unwrap() += 10;
+		assert_eq!(stack.back(), Some(&12));
+		assert_eq!(stack.iter().copied().collect::<Vec<_>>(), [1, 12]);
+		assert_eq!(stack.pop_back(), Some(12));
+		assert_eq!(stack.len(), 1)
+	}
+}
+
+#[test]
+fn set_objects() {
+	let sets: Vec<Box<dyn DynSetMut<String>>> =
+		vec![Box::<HashSet<String>>::default(), Box::<BTreeSet<String>>::default()];
+
+	for mut set in sets {
+		assert!(set.insert("a".to_string()));
+		assert!(!set.insert("a".to_string()));
+		assert!(set.contains(&"a".to_string()));
+		assert_eq!(set.iter().count(), 1);
+		assert_eq!(set.remove(&"a".to_string()).as_deref(), Some("a"));
+		assert!(set.is_empty())
+	}
+}
+
+fn increment<M: MapMut<String, u32>>(map: &mut M, key: &str) {
+	let key = key.to_string();
+	let count = map.remove(&key).unwrap_or(0);
+	map.insert(key, count + 1);
+}
+
+#[test]
+fn map_objects() {
+	let maps: Vec<Box<dyn DynMapMut<String, u32>>> =
+		vec![Box::<HashMap<String, u32>>::default(), Box::<BTreeMap<String, u32>>::default()];
+
+	for map in maps {
+		let mut map = BoxedMap::new(map);
+		increment(&mut map, "a");
+		increment(&mut map, "a");
+		increment(&mut map, "b");
+
+		let mut map = map.into_inner();
+		assert_eq!(map.get(&"a".to_string()), Some(&2));
+		assert_eq!(map.get_key_value(&"b".to_string()), Some((&"b".to_string(), &1)));
+		assert_eq!(map.iter().map(|(_, v)| v).sum::<u32>(), 3);
+
+		assert_eq!(map.remove_entry(&"b".to_string()), Some(("b".to_string(), 1)));
+		assert_eq!(map.len(), 1)
+	}
+}
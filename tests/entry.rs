@@ -0,0 +1,98 @@
+#![cfg(feature = "alloc")]
+use cc_traits::{Entry, EntryApi, Get, OccupiedEntry, VacantEntry};
+use std::collections::BTreeMap;
+
+fn count_words<M>(words: &[&str]) -> M
+where
+	M: Default + EntryApi<String, Key = String, Item = u32>,
+{
+	let mut map = M::default();
+	for word in words {
+		*map.entry(word.to_string()).or_insert(0) += 1
+	}
+	map
+}
+
+fn entry_api<M>()
+where
+	M: Default + EntryApi<String, Key = String, Item = u32> + for<'a> Get<&'a str>,
+	for<'a> M::ItemRef<'a>: Into<&'a u32>,
+{
+	let mut map: M = count_words(&["a", "b", "a"]);
+	assert_eq!(map.get("a").map(Into::into), Some(&2));
+	assert_eq!(map.get("b").map(Into::into), Some(&1));
+
+	let entry = map.entry("c".to_string());
+	assert_eq!(entry.key(), "c");
+	*entry.and_modify(|v| *v = 100).or_insert_with(|| 10) += 1;
+	assert_eq!(map.get("c").map(Into::into), Some(&11));
+
+	*map.entry("c".to_string()).and_modify(|v| *v = 100).or_default() += 1;
+	assert_eq!(map.get("c").map(Into::into), Some(&101));
+
+	map.entry("d".to_string()).or_insert_with_key(|k| k.len() as u32);
+	assert_eq!(map.get("d").map(Into::into), Some(&1));
+
+	match map.entry("a".to_string()) {
+		Entry::Occupied(mut o) => {
+			assert_eq!(o.insert(5), 2);
+			assert_eq!(*o.get(), 5);
+			assert_eq!(o.remove_entry(), ("a".to_string(), 5));
+		}
+		Entry::Vacant(_) => panic!("expected an occupied entry"),
+	}
+	assert!(!map.contains("a"));
+
+	match map.entry("a".to_string()) {
+		Entry::Occupied(_) => panic!("expected a vacant entry"),
+		Entry::Vacant(v) => *v.insert(3) += 1,
+	}
+	assert_eq!(map.get("a").map(Into::into), Some(&4));
+	assert_eq!(map.entry("e".to_string()).remove_entry(), None);
+	assert_eq!(
+		map.entry("b".to_string()).remove_entry(),
+		Some(("b".to_string(), 1))
+	);
+}
+
+#[test]
+fn btree_map_entry() {
+	entry_api::<BTreeMap<String, u32>>()
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hash_map_entry() {
+	entry_api::<std::collections::HashMap<String, u32>>()
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn serde_json_map_entry() {
+	use serde_json::{json, Map, Value};
+	let mut map: Map<String, Value> = Map::new();
+	for key in ["a", "b", "a"] {
+		map.entry(key)
+			.and_modify(|v| *v = json!(v.as_u64().unwrap() + 1))
+			.or_insert(json!(1));
+	}
+	assert_eq!(map.get("a"), Some(&json!(2)));
+	assert_eq!(
+		EntryApi::entry(&mut map, "b").remove_entry(),
+		Some(("b".to_string(), json!(1)))
+	);
+}
+
+#[cfg(feature = "ijson")]
+#[test]
+fn ijson_object_entry() {
+	use ijson::{IObject, IString, IValue};
+	let mut object = IObject::new();
+	EntryApi::entry(&mut object, "a").or_insert(IValue::from(1));
+	EntryApi::entry(&mut object, "a").or_insert(IValue::from(2));
+	assert_eq!(object.get("a"), Some(&IValue::from(1)));
+	assert_eq!(
+		EntryApi::entry(&mut object, "a").remove_entry(),
+		Some((IString::from("a"), IValue::from(1)))
+	);
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "alloc")]
+use cc_traits::{CapacityError, TryPushBack, TryPushError, TryReserve, TryWithCapacity};
+use std::{collections::VecDeque, fmt::Debug};
+
+fn fill<S>(items: &[u32]) -> S
+where
+	S: TryWithCapacity + TryPushBack<Item = u32>,
+	<S as TryWithCapacity>::Error: Debug,
+	<S as TryPushBack>::Error: Debug,
+{
+	let mut result = S::try_with_capacity(items.len()).unwrap();
+	for t in items {
+		result.try_push_back(*t).unwrap();
+	}
+
+	result
+}
+
+#[test]
+fn vec() {
+	let vec: Vec<u32> = fill(&[1, 2, 3]);
+	assert_eq!(vec, [1, 2, 3]);
+
+	let mut vec = vec;
+	assert!(TryReserve::try_reserve(&mut vec, usize::MAX).is_err());
+	assert_eq!(vec.len(), 3);
+}
+
+#[test]
+fn vec_deque() {
+	let deque: VecDeque<u32> = fill(&[1, 2, 3]);
+	assert_eq!(deque, [1, 2, 3]);
+	assert!(<VecDeque<u64> as TryWithCapacity>::try_with_capacity(usize::MAX).is_err());
+}
+
+#[test]
+fn try_push_error() {
+	let mut vec = vec![1u8, 2];
+	assert!(TryPushBack::try_push_back(&mut vec, 3).is_ok());
+	let source = vec.try_reserve(usize::MAX).unwrap_err();
+	let error = TryPushError::new(4u8, source.clone());
+	assert_eq!(error.to_string(), "memory allocation failed");
+	assert_eq!(*error.error(), source);
+	#[cfg(feature = "std")]
+	assert!(std::error::Error::source(&error).is_some());
+	assert_eq!(*error.element(), 4);
+	assert_eq!(error.into_element(), 4);
+	assert_eq!(vec, [1, 2, 3]);
+
+	let mut deque = VecDeque::from([1u8, 2]);
+	assert!(TryPushBack::try_push_back(&mut deque, 3).is_ok());
+	let source = deque.try_reserve(usize::MAX).unwrap_err();
+	assert_eq!(TryPushError::new(4u8, source).into_element(), 4);
+	assert_eq!(deque, [1, 2, 3]);
+}
+
+#[cfg(feature = "smallvec")]
+#[test]
+fn smallvec_try_push_error() {
+	let mut vec: smallvec::SmallVec<[u8; 4]> = smallvec::smallvec![1, 2];
+	assert!(TryPushBack::try_push_back(&mut vec, 3).is_ok());
+	let source = vec.try_reserve(usize::MAX).unwrap_err();
+	assert_eq!(TryPushError::new(4u8, source).into_element(), 4);
+	assert_eq!(vec.as_slice(), [1, 2, 3]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hash_map() {
+	use cc_traits::MapTryInsert;
+	let mut map = std::collections::HashMap::new();
+	assert_eq!(MapTryInsert::try_insert(&mut map, 1, 10), Ok(None));
+	assert_eq!(MapTryInsert::try_insert(&mut map, 1, 20), Ok(Some(10)));
+	assert!(TryReserve::try_reserve(&mut map, usize::MAX).is_err());
+}
+
+#[test]
+fn capacity_error() {
+	let error = CapacityError::new(42);
+	assert_eq!(*error.element(), 42);
+	assert_eq!(error.to_string(), "capacity exceeded");
+	assert_eq!(error.simplify(), CapacityError::new(()));
+}
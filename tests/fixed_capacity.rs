@@ -0,0 +1,66 @@
+#![cfg(all(feature = "arrayvec", feature = "heapless"))]
+use cc_traits::{CapacityError, PushBack, StackMut, TryReserve};
+
+fn push_all<S>(stack: &mut S, items: &[u32]) -> Result<(), CapacityError<u32>>
+where
+	S: StackMut<u32> + PushBack<Output = Result<(), CapacityError<u32>>>,
+{
+	for t in items {
+		stack.push_back(*t)?;
+	}
+
+	Ok(())
+}
+
+#[test]
+fn array_vec() {
+	let mut vec: arrayvec::ArrayVec<u32, 3> = arrayvec::ArrayVec::new();
+	assert_eq!(push_all(&mut vec, &[1, 2, 3]), Ok(()));
+	assert_eq!(push_all(&mut vec, &[4]), Err(CapacityError::new(4)));
+	assert_eq!(vec.as_slice(), [1, 2, 3]);
+	assert!(TryReserve::try_reserve(&mut vec, 1).is_err());
+}
+
+#[test]
+fn heapless_vec() {
+	let mut vec: heapless::Vec<u32, 2> = heapless::Vec::new();
+	assert_eq!(push_all(&mut vec, &[1, 2, 3]), Err(CapacityError::new(3)));
+	assert_eq!(vec.as_slice(), [1, 2]);
+}
+
+#[test]
+fn heapless_deque() {
+	use cc_traits::{Iter, PopFront, PushFront};
+	let mut deque: heapless::Deque<u32, 3> = heapless::Deque::new();
+	assert_eq!(push_all(&mut deque, &[2, 3]), Ok(()));
+	assert_eq!(PushFront::push_front(&mut deque, 1), Ok(()));
+	assert_eq!(PushFront::push_front(&mut deque, 0), Err(CapacityError::new(0)));
+	assert_eq!(Iter::iter(&deque).copied().sum::<u32>(), 6);
+	assert_eq!(PopFront::pop_front(&mut deque), Some(1));
+}
+
+#[test]
+fn heapless_index_map() {
+	use cc_traits::{GetKeyValue, MapInsert, Remove};
+	let mut map: heapless::FnvIndexMap<u32, u32, 2> = heapless::FnvIndexMap::new();
+	assert_eq!(MapInsert::insert(&mut map, 1, 10), Ok(None));
+	assert_eq!(MapInsert::insert(&mut map, 2, 20), Ok(None));
+	assert_eq!(
+		MapInsert::insert(&mut map, 3, 30),
+		Err(CapacityError::new((3, 30)))
+	);
+	assert_eq!(map.get_key_value(&2), Some((&2, &20)));
+	assert_eq!(Remove::remove(&mut map, &1), Some(10));
+}
+
+#[test]
+fn heapless_index_set() {
+	use cc_traits::{Get, Insert};
+	let mut set: heapless::FnvIndexSet<u32, 2> = heapless::FnvIndexSet::new();
+	assert_eq!(Insert::insert(&mut set, 1), Ok(true));
+	assert_eq!(Insert::insert(&mut set, 1), Ok(false));
+	assert_eq!(Insert::insert(&mut set, 2), Ok(true));
+	assert_eq!(Insert::insert(&mut set, 3), Err(CapacityError::new(3)));
+	assert_eq!(Get::get(&set, &2), Some(&2));
+	assert_eq!(Get::get(&set, &3), None);
+}
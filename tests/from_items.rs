@@ -0,0 +1,66 @@
+#![cfg(feature = "alloc")]
+use cc_traits::{ExtendItems, ExtendMap, FromItems, FromMap, Iter, MapIter};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+fn collect<C>(items: &[u32]) -> C
+where
+	C: FromItems + ExtendItems + cc_traits::Collection<Item = u32>,
+{
+	let (head, tail) = items.split_at(items.len() / 2);
+	let mut result = C::from_items(head.iter().copied());
+	result.extend_items(tail.iter().copied());
+	result
+}
+
+fn collect_map<M>(pairs: &[(u32, u32)]) -> M
+where
+	M: FromMap<u32> + ExtendMap<u32> + cc_traits::Collection<Item = u32>,
+{
+	let (head, tail) = pairs.split_at(pairs.len() / 2);
+	let mut result = M::from_map(head.iter().copied());
+	result.extend_map(tail.iter().copied());
+	result
+}
+
+#[test]
+fn sequences() {
+	let vec: Vec<u32> = collect(&[1, 2, 3, 4, 5]);
+	assert_eq!(vec, [1, 2, 3, 4, 5]);
+
+	let deque: VecDeque<u32> = collect(&[1, 2, 3]);
+	assert_eq!(deque.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+}
+
+#[test]
+fn reserves_size_hint() {
+	let vec = Vec::<u32>::from_items(0..100);
+	assert!(vec.capacity() >= 100);
+
+	let boxed = Box::<Vec<u32>>::from_items(0..100);
+	assert!(boxed.capacity() >= 100);
+}
+
+#[test]
+fn sets() {
+	let set: BTreeSet<u32> = collect(&[3, 1, 3, 2]);
+	assert_eq!(set.len(), 3);
+	assert_eq!(Iter::iter(&set).copied().collect::<Vec<_>>(), [1, 2, 3]);
+}
+
+#[test]
+fn maps() {
+	let map: BTreeMap<u32, u32> = collect_map(&[(1, 10), (2, 20), (1, 30)]);
+	assert_eq!(map.len(), 2);
+	let pairs: Vec<(u32, u32)> = MapIter::iter(&map).map(|(k, v)| (*k, *v)).collect();
+	assert_eq!(pairs, [(1, 30), (2, 20)]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hash_map() {
+	let map: std::collections::HashMap<u32, u32> = collect_map(&[(1, 10), (2, 20)]);
+	assert_eq!(map.get(&2), Some(&20));
+
+	let map = std::collections::HashMap::<u32, u32>::from_map((0..100).map(|i| (i, i)));
+	assert!(map.capacity() >= 100);
+}
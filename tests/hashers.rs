@@ -0,0 +1,39 @@
+#![cfg(feature = "std")]
+use cc_traits::{EntryApi, MapMut, SetMut};
+use std::{collections::hash_map::DefaultHasher, hash::BuildHasherDefault};
+
+type CustomHasher = BuildHasherDefault<DefaultHasher>;
+
+fn count<M>(map: &mut M, words: &[&'static str])
+where
+	M: MapMut<&'static str, usize> + EntryApi<&'static str>,
+{
+	for w in words {
+		*map.entry(*w).or_insert(0) += 1;
+	}
+}
+
+fn dedup<S: SetMut<u32>>(set: &mut S, items: &[u32]) -> usize {
+	items.iter().filter(|t| set.insert(**t)).count()
+}
+
+#[test]
+fn std_custom_hasher() {
+	let mut map: std::collections::HashMap<&str, usize, CustomHasher> = Default::default();
+	count(&mut map, &["a", "b", "a"]);
+	assert_eq!(map["a"], 2);
+
+	let mut set: std::collections::HashSet<u32, CustomHasher> = Default::default();
+	assert_eq!(dedup(&mut set, &[1, 2, 1, 3]), 3);
+}
+
+#[cfg(feature = "hashbrown")]
+#[test]
+fn hashbrown_custom_hasher() {
+	let mut map: hashbrown::HashMap<&str, usize, CustomHasher> = Default::default();
+	count(&mut map, &["a", "b", "a"]);
+	assert_eq!(map["a"], 2);
+
+	let mut set: hashbrown::HashSet<u32, CustomHasher> = Default::default();
+	assert_eq!(dedup(&mut set, &[1, 2, 1, 3]), 3);
+}
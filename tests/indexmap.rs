@@ -0,0 +1,64 @@
+#![cfg(all(feature = "std", feature = "indexmap"))]
+use cc_traits::{
+	FirstKeyValue, GetIndex, LastKeyValue, MapMut, Remove, SetMut, Shifting, Swapping,
+};
+use indexmap::{IndexMap, IndexSet};
+use std::collections::{hash_map::RandomState, BTreeMap};
+
+fn fill_map<M: MapMut<u32, char>>(map: &mut M) {
+	for (k, v) in [(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')] {
+		map.insert(k, v);
+	}
+}
+
+fn fill_set<S: SetMut<u32>>(set: &mut S) {
+	for t in [1, 2, 3, 4] {
+		set.insert(t);
+	}
+}
+
+#[test]
+fn map() {
+	let mut map: IndexMap<u32, char, RandomState> = IndexMap::default();
+	fill_map(&mut map);
+	assert_eq!(GetIndex::get_index(&map, 0), Some((&1, &'a')));
+	assert_eq!(GetIndex::get_index(&map, 4), None);
+
+	assert_eq!(Remove::remove(&mut map, Shifting(&1)), Some('a'));
+	let keys: Vec<u32> = map.keys().copied().collect();
+	assert_eq!(keys, [2, 3, 4]);
+
+	assert_eq!(Remove::remove(&mut map, Swapping(&2)), Some('b'));
+	let keys: Vec<u32> = map.keys().copied().collect();
+	assert_eq!(keys, [4, 3]);
+}
+
+#[test]
+fn set() {
+	let mut set: IndexSet<u32, RandomState> = IndexSet::default();
+	fill_set(&mut set);
+	assert_eq!(cc_traits::Get::get(&set, 1usize), Some(&2));
+
+	assert_eq!(Remove::remove(&mut set, Shifting(&1)), Some(1));
+	assert_eq!(Remove::remove(&mut set, Swapping(&2)), Some(2));
+	let items: Vec<u32> = set.iter().copied().collect();
+	assert_eq!(items, [4, 3]);
+}
+
+#[test]
+fn positional_ends() {
+	let mut map: IndexMap<u32, char, RandomState> = IndexMap::default();
+	for (k, v) in [(3, 'c'), (1, 'a'), (4, 'd'), (2, 'b')] {
+		map.insert(k, v);
+	}
+
+	// The ends by position follow the insertion order, not the key order,
+	// which is why `IndexMap` does not implement the ordered traits.
+	let last = map.len() - 1;
+	assert_eq!(GetIndex::get_index(&map, 0), Some((&3, &'c')));
+	assert_eq!(GetIndex::get_index(&map, last), Some((&2, &'b')));
+
+	let sorted: BTreeMap<u32, char> = map.into_iter().collect();
+	assert_eq!(FirstKeyValue::first_key_value(&sorted), Some((&1, &'a')));
+	assert_eq!(LastKeyValue::last_key_value(&sorted), Some((&4, &'d')));
+}
@@ -0,0 +1,108 @@
+#![cfg(feature = "alloc")]
+use cc_traits::{IntoKeys, IntoValues, Keys, MapInsert, Values, ValuesMut};
+use std::collections::BTreeMap;
+
+fn keys_values<M>()
+where
+	M: Default
+		+ Clone
+		+ MapInsert<&'static str>
+		+ Keys<Key = &'static str, Item = u32>
+		+ Values
+		+ ValuesMut
+		+ IntoKeys
+		+ IntoValues,
+	for<'a> M::KeyRef<'a>: Into<&'a &'static str>,
+	for<'a> M::ItemRef<'a>: Into<&'a u32>,
+	for<'a> M::ItemMut<'a>: Into<&'a mut u32>,
+{
+	let mut map = M::default();
+	map.insert("a", 1);
+	map.insert("b", 2);
+	map.insert("c", 3);
+
+	let mut keys: Vec<&str> = map.keys().map(|k| *k.into()).collect();
+	keys.sort_unstable();
+	assert_eq!(keys, ["a", "b", "c"]);
+
+	for v in map.values_mut() {
+		*v.into() *= 10
+	}
+	assert_eq!(map.values().map(|v| *v.into()).sum::<u32>(), 60);
+
+	let mut keys: Vec<_> = map.clone().into_keys().collect();
+	keys.sort_unstable();
+	assert_eq!(keys, ["a", "b", "c"]);
+
+	let mut values: Vec<_> = map.into_values().collect();
+	values.sort_unstable();
+	assert_eq!(values, [10, 20, 30]);
+}
+
+#[test]
+fn btree_map() {
+	keys_values::<BTreeMap<_, _>>()
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hash_map() {
+	keys_values::<std::collections::HashMap<_, _>>()
+}
+
+#[cfg(all(feature = "hashbrown", feature = "std"))]
+#[test]
+fn hashbrown_map() {
+	keys_values::<hashbrown::HashMap<_, _, std::collections::hash_map::RandomState>>()
+}
+
+#[cfg(all(feature = "indexmap", feature = "std"))]
+#[test]
+fn index_map() {
+	keys_values::<indexmap::IndexMap<_, _, std::collections::hash_map::RandomState>>()
+}
+
+#[test]
+fn boxed() {
+	let map = Box::new(BTreeMap::from([(1, 'a'), (2, 'b')]));
+	assert_eq!(Keys::keys(&&map).collect::<Vec<_>>(), [&1, &2]);
+	assert_eq!(IntoValues::into_values(map).collect::<String>(), "ab");
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn serde_json_map() {
+	use serde_json::{json, Map, Value};
+	let mut map: Map<String, Value> = Map::new();
+	map.insert("a".to_string(), json!(1));
+	map.insert("b".to_string(), json!(2));
+
+	for v in ValuesMut::values_mut(&mut map) {
+		*v = json!(v.as_u64().unwrap() * 2)
+	}
+	assert_eq!(Keys::keys(&map).collect::<Vec<_>>(), ["a", "b"]);
+	assert_eq!(Values::values(&map).collect::<Vec<_>>(), [&json!(2), &json!(4)]);
+	assert_eq!(IntoKeys::into_keys(map).collect::<Vec<_>>(), ["a", "b"]);
+}
+
+#[cfg(feature = "ijson")]
+#[test]
+fn ijson_object() {
+	use ijson::{IObject, IValue};
+	let mut object = IObject::new();
+	object.insert("a", IValue::from(1));
+	object.insert("b", IValue::from(2));
+
+	for v in ValuesMut::values_mut(&mut object) {
+		*v = IValue::from(v.to_u64().unwrap() * 2)
+	}
+	assert_eq!(Keys::keys(&object).count(), 2);
+	assert_eq!(
+		Values::values(&object).map(|v| v.to_u64().unwrap()).sum::<u64>(),
+		6
+	);
+	assert_eq!(IntoValues::into_values(object.clone()).count(), 2);
+	let mut keys: Vec<_> = IntoKeys::into_keys(object).map(|k| k.to_string()).collect();
+	keys.sort_unstable();
+	assert_eq!(keys, ["a", "b"]);
+}
@@ -0,0 +1,88 @@
+#![cfg(feature = "testing")]
+use cc_traits::testing::{check_map_mut, check_set_mut, check_slab_mut, check_stack_mut};
+use std::collections::{BTreeMap, BTreeSet, LinkedList, VecDeque};
+
+#[test]
+fn stacks() {
+	check_stack_mut::<Vec<u32>, u32>();
+	check_stack_mut::<VecDeque<String>, String>();
+	check_stack_mut::<LinkedList<char>, char>();
+	check_stack_mut::<Box<Vec<i64>>, i64>();
+}
+
+#[test]
+fn sets() {
+	check_set_mut::<BTreeSet<u32>, u32>();
+	check_set_mut::<Box<BTreeSet<String>>, String>();
+}
+
+#[test]
+fn maps() {
+	check_map_mut::<BTreeMap<String, u32>, String, u32>();
+	check_map_mut::<Box<BTreeMap<u8, bool>>, u8, bool>();
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn std() {
+	use std::collections::{HashMap, HashSet};
+	check_set_mut::<HashSet<u32>, u32>();
+	check_map_mut::<HashMap<String, u32>, String, u32>();
+}
+
+#[cfg(feature = "smallvec")]
+#[test]
+fn smallvec() {
+	check_stack_mut::<smallvec::SmallVec<[u32; 4]>, u32>();
+}
+
+#[cfg(feature = "arrayvec")]
+#[test]
+fn arrayvec() {
+	check_stack_mut::<arrayvec::ArrayVec<u32, 256>, u32>();
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn heapless() {
+	check_stack_mut::<heapless::Vec<u32, 256>, u32>();
+	check_stack_mut::<heapless::Deque<u32, 256>, u32>();
+}
+
+#[cfg(feature = "slab")]
+#[test]
+fn slab() {
+	check_slab_mut::<slab::Slab<u32>, u32>();
+	check_slab_mut::<slab::Slab<String>, String>();
+}
+
+#[cfg(feature = "indexmap")]
+#[test]
+fn indexmap() {
+	use std::collections::hash_map::RandomState;
+	check_set_mut::<indexmap::IndexSet<u32, RandomState>, u32>();
+	check_map_mut::<indexmap::IndexMap<String, u32, RandomState>, String, u32>();
+}
+
+#[cfg(feature = "hashbrown")]
+#[test]
+fn hashbrown() {
+	use std::collections::hash_map::RandomState;
+	check_set_mut::<hashbrown::HashSet<u32, RandomState>, u32>();
+	check_map_mut::<hashbrown::HashMap<String, u32, RandomState>, String, u32>();
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn serde_json() {
+	use serde_json::{Map, Value};
+	check_map_mut::<Map<String, Value>, String, Value>();
+}
+
+#[cfg(feature = "ijson")]
+#[test]
+fn ijson() {
+	use ijson::{IArray, IObject, IString, IValue};
+	check_stack_mut::<IArray, IValue>();
+	check_map_mut::<IObject, IString, IValue>();
+}
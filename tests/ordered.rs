@@ -0,0 +1,93 @@
+#![cfg(feature = "alloc")]
+use cc_traits::{OrderedMapMut, OrderedSetMut};
+use std::{
+	collections::{BTreeMap, BTreeSet},
+	ops::Bound,
+};
+
+fn ordered_set<S>()
+where
+	S: Default + OrderedSetMut<u32>,
+	for<'a> S::ItemRef<'a>: Into<&'a u32>,
+{
+	let mut set = S::default();
+	for t in [5, 1, 3, 9, 7] {
+		set.insert(t);
+	}
+
+	assert_eq!(set.first().map(Into::into), Some(&1));
+	assert_eq!(set.last().map(Into::into), Some(&9));
+	assert_eq!(set.floor(&4).map(Into::into), Some(&3));
+	assert_eq!(set.floor(&5).map(Into::into), Some(&5));
+	assert_eq!(set.floor(&0).map(Into::into), None);
+	assert_eq!(set.ceiling(&4).map(Into::into), Some(&5));
+	assert_eq!(set.ceiling(&10).map(Into::into), None);
+
+	let range: Vec<u32> = set
+		.range((Bound::Included(&3), Bound::Excluded(&9)))
+		.map(|t| *t.into())
+		.collect();
+	assert_eq!(range, [3, 5, 7]);
+
+	let all: Vec<u32> = set.iter().map(|t| *t.into()).collect();
+	assert_eq!(all, [1, 3, 5, 7, 9]);
+
+	assert_eq!(set.pop_first(), Some(1));
+	assert_eq!(set.pop_last(), Some(9));
+	assert_eq!(set.len(), 3);
+}
+
+fn ordered_map<M>()
+where
+	M: Default + OrderedMapMut<u32, char>,
+	for<'a> M::KeyRef<'a>: Into<&'a u32>,
+	for<'a> M::ItemRef<'a>: Into<&'a char>,
+{
+	let mut map = M::default();
+	for (k, v) in [(2, 'b'), (1, 'a'), (4, 'd')] {
+		map.insert(k, v);
+	}
+
+	let pair = |(k, v): (M::KeyRef<'_>, M::ItemRef<'_>)| (*k.into(), *v.into());
+	assert_eq!(map.first_key_value().map(pair), Some((1, 'a')));
+	assert_eq!(map.last_key_value().map(pair), Some((4, 'd')));
+	assert_eq!(map.floor_key_value(&3).map(pair), Some((2, 'b')));
+	assert_eq!(map.ceiling_key_value(&3).map(pair), Some((4, 'd')));
+	assert_eq!(map.ceiling_key_value(&5).map(pair), None);
+
+	let range: Vec<(u32, char)> = map
+		.range((Bound::Excluded(&1), Bound::Unbounded))
+		.map(pair)
+		.collect();
+	assert_eq!(range, [(2, 'b'), (4, 'd')]);
+
+	assert_eq!(map.pop_first_key_value(), Some((1, 'a')));
+	assert_eq!(map.pop_last_key_value(), Some((4, 'd')));
+	assert_eq!(map.len(), 1);
+}
+
+#[test]
+fn btree_set_ordered() {
+	ordered_set::<BTreeSet<u32>>()
+}
+
+#[test]
+fn btree_map_ordered() {
+	ordered_map::<BTreeMap<u32, char>>()
+}
+
+#[test]
+fn btree_map_values() {
+	use cc_traits::{Ceiling, First, Floor, Last, PopFirst, PopLast, Range};
+	let mut map = BTreeMap::from([(1, 'a'), (2, 'b'), (3, 'c')]);
+	assert_eq!(First::first(&map), Some(&'a'));
+	assert_eq!(Last::last(&map), Some(&'c'));
+	assert_eq!(Floor::floor(&map, &5), Some(&'c'));
+	assert_eq!(Ceiling::ceiling(&map, &0), Some(&'a'));
+	assert_eq!(
+		Range::<u32, _>::range(&map, 2..).collect::<Vec<_>>(),
+		[&'b', &'c']
+	);
+	assert_eq!(PopFirst::pop_first(&mut map), Some('a'));
+	assert_eq!(PopLast::pop_last(&mut map), Some('c'));
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "alloc")]
+use cc_traits::{
+	Get, Iter, Len, MapInsert, MapIter, MapMut, OrderedSet, SimpleCollectionRef, Stack,
+	StackMut,
+};
+use std::{
+	collections::{BTreeMap, BTreeSet, VecDeque},
+	rc::Rc,
+	sync::Arc,
+};
+
+fn sum<S: Stack<u32> + Iter + SimpleCollectionRef>(stack: S) -> u32 {
+	stack.iter().map(|t| *S::into_ref(t)).sum()
+}
+
+fn push_pop<S: StackMut<u32>>(mut stack: S) -> Option<u32> {
+	stack.push_back(3);
+	*stack.back_mut().unwrap() += 1;
+	stack.pop_back()
+}
+
+fn has<S: OrderedSet<u32>>(set: S, t: u32) -> bool {
+	set.contains(&t) && set.first().is_some()
+}
+
+fn upsert<M: MapMut<&'static str, u32>>(mut map: M) {
+	MapInsert::insert(&mut map, "a", 1);
+	MapInsert::insert(&mut map, "b", 2);
+	assert_eq!(map.remove(&"a"), Some(1))
+}
+
+fn keys<M: MapIter<Key = &'static str>>(map: M) -> Vec<&'static str> {
+	map.iter().map(|(k, _)| *k).collect()
+}
+
+#[test]
+fn shared_references() {
+	let vec = vec![1, 2, 3];
+	assert_eq!(sum(&vec), 6);
+	assert_eq!(sum(Rc::new(&vec)), 6);
+	assert_eq!(sum(Rc::new(vec.clone())), 6);
+	assert_eq!(sum(Arc::new(VecDeque::from(vec.clone()))), 6);
+	assert_eq!(sum(Box::new(vec)), 6);
+
+	let set = BTreeSet::from([1, 2]);
+	assert!(has(&set, 1));
+	assert!(!has(Arc::new(set), 3));
+}
+
+#[test]
+fn mutable_references() {
+	let mut vec = vec![1, 2];
+	assert_eq!(push_pop(&mut vec), Some(4));
+	assert_eq!(push_pop(Box::new(&mut vec)), Some(4));
+	assert_eq!(push_pop(Box::new(VecDeque::from(vec))), Some(4));
+
+	let mut map = BTreeMap::new();
+	upsert(&mut map);
+	upsert(Box::new(BTreeMap::new()));
+	assert_eq!(keys(&map), ["b"]);
+	assert_eq!(keys(Rc::new(map)), ["b"]);
+}
+
+#[test]
+fn item_references() {
+	let vec = vec![1, 2, 3];
+	let r = &vec;
+	let first: &u32 = Get::get(&r, 0).unwrap();
+	let rc = Rc::new(r);
+	let last: &u32 = Get::get(&rc, 2).unwrap();
+	assert_eq!((*first, *last), (1, 3));
+	assert_eq!(Len::len(&Box::new(r)), 3);
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "alloc")]
+use cc_traits::{Peek, PeekMut, PopMax, PriorityQueue, PriorityQueueMut, PushPriority};
+use std::collections::{BTreeSet, BinaryHeap};
+
+fn schedule<Q>() -> Vec<u32>
+where
+	Q: Default + PriorityQueueMut<u32>,
+{
+	let mut queue = Q::default();
+	for t in [3, 1, 4, 5, 9, 2, 6] {
+		queue.push(t);
+	}
+
+	assert_eq!(queue.len(), 7);
+	assert_eq!(queue.peek().map(|t| *t), Some(9));
+
+	let mut order = Vec::new();
+	while let Some(t) = queue.pop_max() {
+		order.push(t)
+	}
+
+	assert!(queue.is_empty());
+	assert_eq!(queue.peek().map(|t| *t), None);
+	order
+}
+
+#[test]
+fn binary_heap() {
+	assert_eq!(schedule::<BinaryHeap<u32>>(), [9, 6, 5, 4, 3, 2, 1]);
+
+	let mut heap = BinaryHeap::from([1, 5, 3]);
+	*PeekMut::peek_mut(&mut heap).unwrap() = 2;
+	assert_eq!(heap.into_sorted_vec(), [1, 2, 3]);
+}
+
+/// Pushes `items` in a new queue, then pops them all.
+fn push_pop<Q>(items: &[u32]) -> Vec<u32>
+where
+	Q: Default + PriorityQueue<u32> + PushPriority + PopMax,
+{
+	let mut queue = Q::default();
+	for t in items {
+		queue.push(*t);
+	}
+
+	let mut order = Vec::new();
+	while let Some(t) = queue.pop_max() {
+		order.push(t)
+	}
+	order
+}
+
+#[test]
+fn btree_set() {
+	let mut set = BTreeSet::new();
+	assert!(PushPriority::push(&mut set, 2));
+	assert!(!PushPriority::push(&mut set, 2));
+	assert_eq!(Peek::peek(&set).copied(), Some(2));
+	assert_eq!(PopMax::pop_max(&mut set), Some(2));
+	assert_eq!(PopMax::pop_max(&mut set), None);
+}
+
+#[test]
+fn duplicate_priorities() {
+	let items = [3, 1, 3, 2, 1, 3];
+	assert_eq!(push_pop::<BinaryHeap<u32>>(&items), [3, 3, 3, 2, 1, 1]);
+	assert_eq!(push_pop::<BTreeSet<u32>>(&items), [3, 2, 1]);
+}
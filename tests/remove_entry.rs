@@ -0,0 +1,89 @@
+#![cfg(feature = "alloc")]
+use cc_traits::{GetKeyValueMut, MapMut};
+use std::collections::BTreeMap;
+
+fn take<M: MapMut<String, u32>>(map: &mut M, key: &String) -> Option<(String, u32)> {
+	map.remove_entry(key)
+}
+
+fn rename<M>(mut map: M) -> M
+where
+	M: MapMut<String, u32> + for<'a> GetKeyValueMut<&'a String>,
+	for<'a> M::KeyRef<'a>: Into<&'a String>,
+	for<'a> M::ItemMut<'a>: Into<&'a mut u32>,
+{
+	map.insert("a".to_string(), 1);
+	map.insert("b".to_string(), 2);
+
+	{
+		let (k, v) = map.get_key_value_mut(&"a".to_string()).unwrap();
+		*v.into() += k.into().len() as u32;
+	}
+	assert!(map.get_key_value_mut(&"c".to_string()).is_none());
+
+	let (k, v) = take(&mut map, &"a".to_string()).unwrap();
+	assert_eq!((k.as_str(), v), ("a", 2));
+	assert_eq!(take(&mut map, &"a".to_string()), None);
+	map.insert(k.to_uppercase(), v);
+	map
+}
+
+#[test]
+fn btree_map() {
+	let map = rename(BTreeMap::new());
+	assert_eq!(map, BTreeMap::from([("A".to_string(), 2), ("b".to_string(), 2)]));
+}
+
+#[cfg(all(feature = "hashbrown", feature = "std"))]
+#[test]
+fn hashbrown_map() {
+	use std::collections::hash_map::RandomState;
+	let map = rename(hashbrown::HashMap::<_, _, RandomState>::default());
+	assert_eq!(map.get("A"), Some(&2));
+}
+
+#[cfg(all(feature = "indexmap", feature = "std"))]
+#[test]
+fn index_map() {
+	use cc_traits::Shifting;
+	use std::collections::hash_map::RandomState;
+	let mut map = rename(indexmap::IndexMap::<_, _, RandomState>::default());
+	map.insert("c".to_string(), 3);
+	assert_eq!(
+		cc_traits::RemoveEntry::remove_entry(&mut map, Shifting(&"b".to_string())),
+		Some(("b".to_string(), 2))
+	);
+	assert_eq!(map.keys().collect::<Vec<_>>(), ["A", "c"]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hash_map() {
+	let mut map = std::collections::HashMap::new();
+	map.insert("a".to_string(), 1);
+	assert_eq!(take(&mut map, &"a".to_string()), Some(("a".to_string(), 1)));
+	assert!(map.is_empty());
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn serde_json_map() {
+	let mut map = serde_json::Map::new();
+	map.insert("a".to_string(), serde_json::json!(1));
+	assert_eq!(
+		cc_traits::RemoveEntry::remove_entry(&mut map, "a"),
+		Some(("a".to_string(), serde_json::json!(1)))
+	);
+}
+
+#[cfg(feature = "ijson")]
+#[test]
+fn ijson_object() {
+	use ijson::{IObject, IString, IValue};
+	let mut object = IObject::new();
+	object.insert("a", IValue::from(1));
+	assert_eq!(
+		cc_traits::RemoveEntry::remove_entry(&mut object, "a"),
+		Some((IString::from("a"), IValue::from(1)))
+	);
+}
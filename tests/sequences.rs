@@ -0,0 +1,120 @@
+#![cfg(feature = "alloc")]
+use cc_traits::{Append, Resize, ResizeWith, SplitOff, VecMut};
+use std::collections::{BTreeMap, VecDeque};
+
+fn edit<V>(mut v: V) -> V
+where
+	V: VecMut<u32> + Resize + ResizeWith + SplitOff + Append,
+{
+	for t in [1, 2, 3] {
+		v.push_back(t);
+	}
+
+	v.insert_at(0, 0);
+	v.insert_at(4, 4);
+	assert_eq!(v.len(), 5);
+	assert_eq!(v.swap_remove(1), Some(1));
+	assert_eq!(v.swap_remove(5), None);
+	assert_eq!(v[1], 4);
+
+	v.truncate(3);
+	v.resize(5, 7);
+	let mut next = 10;
+	v.resize_with(6, || {
+		next += 1;
+		next
+	});
+
+	let mut tail = v.split_off(4);
+	assert_eq!(v.len(), 4);
+	assert_eq!(tail.len(), 2);
+
+	tail.append(&mut v);
+	assert!(v.is_empty());
+	tail
+}
+
+#[test]
+fn vec() {
+	assert_eq!(edit(Vec::new()), [7, 11, 0, 4, 2, 7]);
+}
+
+#[test]
+fn vec_deque() {
+	assert_eq!(edit(VecDeque::new()), [7, 11, 0, 4, 2, 7]);
+}
+
+#[cfg(feature = "smallvec")]
+#[test]
+fn smallvec() {
+	let v: smallvec::SmallVec<[u32; 4]> = edit(smallvec::SmallVec::new());
+	assert_eq!(v.as_slice(), [7, 11, 0, 4, 2, 7]);
+}
+
+#[cfg(feature = "ijson")]
+#[test]
+fn iarray() {
+	use cc_traits::{InsertAt, SwapRemove, Truncate};
+	use ijson::{IArray, IValue};
+
+	let mut array: IArray = (0..4).map(IValue::from).collect();
+	array.insert_at(1, IValue::from(10));
+	assert_eq!(SwapRemove::swap_remove(&mut array, 0), Some(IValue::from(0)));
+	Truncate::truncate(&mut array, 3);
+	Resize::resize(&mut array, 4, IValue::NULL);
+	let mut tail = SplitOff::split_off(&mut array, 2);
+	assert_eq!(tail.len(), 2);
+	Append::append(&mut tail, &mut array);
+	assert!(array.is_empty());
+	let expected = [
+		IValue::from(1),
+		IValue::NULL,
+		IValue::from(3),
+		IValue::from(10),
+	];
+	assert_eq!(tail.as_slice(), expected);
+}
+
+#[cfg(feature = "ijson")]
+#[test]
+fn iarray_split_off() {
+	use ijson::{IArray, IValue};
+
+	for at in [0, 3, 6] {
+		let mut vec: Vec<u32> = (0..6).collect();
+		let mut array: IArray = (0..6u32).collect();
+		let vec_tail = vec.split_off(at);
+		let tail = SplitOff::split_off(&mut array, at);
+
+		let values = |items: Vec<u32>| items.into_iter().map(IValue::from).collect::<Vec<_>>();
+		assert_eq!(array.as_slice(), values(vec));
+		assert_eq!(tail.as_slice(), values(vec_tail));
+	}
+}
+
+#[cfg(feature = "ijson")]
+#[test]
+#[should_panic(expected = "split index is out of bounds")]
+fn iarray_split_off_out_of_bounds() {
+	let mut array: ijson::IArray = (0..2u32).collect();
+	SplitOff::split_off(&mut array, 3);
+}
+
+#[test]
+fn append_maps() {
+	let mut a = BTreeMap::from([(1, "a"), (2, "b")]);
+	let mut b = BTreeMap::from([(2, "c"), (3, "d")]);
+	Append::append(&mut a, &mut b);
+	assert!(b.is_empty());
+	assert_eq!(a, BTreeMap::from([(1, "a"), (2, "c"), (3, "d")]));
+
+	#[cfg(feature = "std")]
+	{
+		use std::collections::HashSet;
+		let mut a = HashSet::from([1, 2]);
+		let mut b = HashSet::from([2, 3]);
+		Append::append(&mut a, &mut b);
+		assert!(b.is_empty());
+		assert_eq!(a, HashSet::from([1, 2, 3]));
+	}
+}
@@ -0,0 +1,83 @@
+#![cfg(all(feature = "serde", feature = "serde_json", feature = "std"))]
+use cc_traits::serde::{map, seq, SerializeMap, SerializeSeq, SerializeSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, LinkedList, VecDeque};
+
+fn round_trip<T>(value: &T) -> T
+where
+	T: serde::Serialize + serde::de::DeserializeOwned,
+{
+	serde_json::from_str(&serde_json::to_string(value).unwrap()).unwrap()
+}
+
+#[test]
+fn sequences() {
+	assert_eq!(serde_json::to_string(&SerializeSeq(vec![1, 2, 3])).unwrap(), "[1,2,3]");
+	assert_eq!(serde_json::to_string(&SerializeSeq([1, 2, 3])).unwrap(), "[1,2,3]");
+
+	let deque: VecDeque<_> = (0..4).collect();
+	assert_eq!(round_trip(&SerializeSeq(deque.clone())).0, deque);
+
+	let list: LinkedList<_> = ["a", "b"].iter().map(|s| s.to_string()).collect();
+	assert_eq!(round_trip(&SerializeSeq(list.clone())).0, list);
+
+	let SerializeSeq(v): SerializeSeq<Vec<u8>> = serde_json::from_str("[4, 5]").unwrap();
+	assert_eq!(v, [4, 5]);
+	assert!(serde_json::from_str::<SerializeSeq<Vec<u8>>>("{}").is_err());
+}
+
+#[test]
+fn sets() {
+	let set = BTreeSet::from([3, 1, 2]);
+	assert_eq!(serde_json::to_string(&SerializeSet(set.clone())).unwrap(), "[1,2,3]");
+	assert_eq!(round_trip(&SerializeSet(set.clone())).0, set);
+
+	let SerializeSet(set): SerializeSet<BTreeSet<u8>> = serde_json::from_str("[1, 1, 2]").unwrap();
+	assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn maps() {
+	let map = BTreeMap::from([("a".to_string(), 1), ("b".to_string(), 2)]);
+	assert_eq!(serde_json::to_string(&SerializeMap(map.clone())).unwrap(), r#"{"a":1,"b":2}"#);
+	assert_eq!(round_trip(&SerializeMap(map.clone())).0, map);
+
+	let map: HashMap<_, _> = map.into_iter().collect();
+	assert_eq!(round_trip(&SerializeMap(map.clone())).0, map);
+}
+
+#[test]
+fn with_modules() {
+	let mut buffer = Vec::new();
+	let map = BTreeMap::from([(1, 'a')]);
+	map::serialize(&map, &mut serde_json::Serializer::new(&mut buffer)).unwrap();
+	assert_eq!(buffer, br#"{"1":"a"}"#);
+
+	let mut deserializer = serde_json::Deserializer::from_slice(&buffer);
+	let json: serde_json::Map<String, serde_json::Value> =
+		map::deserialize(&mut deserializer).unwrap();
+	assert_eq!(json["1"], "a");
+
+	let word = "borrowed".to_string();
+	let mut serializer = serde_json::Serializer::new(Vec::new());
+	seq::serialize(&vec![word.as_str()], &mut serializer).unwrap();
+	assert_eq!(serializer.into_inner(), br#"["borrowed"]"#);
+
+	let mut deserializer = serde_json::Deserializer::from_str("[true, false]");
+	let flags: VecDeque<bool> = seq::deserialize(&mut deserializer).unwrap();
+	assert_eq!(flags, [true, false]);
+}
+
+#[cfg(feature = "smallvec")]
+#[test]
+fn smallvec() {
+	let v: smallvec::SmallVec<[u32; 2]> = smallvec::smallvec![1, 2, 3];
+	assert_eq!(round_trip(&SerializeSeq(v.clone())).0, v);
+}
+
+#[cfg(feature = "ijson")]
+#[test]
+fn ijson() {
+	let mut object = ijson::IObject::new();
+	object.insert("a", ijson::IValue::from(1));
+	assert_eq!(round_trip(&SerializeMap(object.clone())).0, object);
+}
@@ -0,0 +1,103 @@
+#![cfg(feature = "alloc")]
+use cc_traits::{SetAlgebra, SetAlgebraMut, SetMut, UnionWith};
+use std::collections::BTreeSet;
+
+fn set_from<S: SetMut<u32> + Default>(items: &[u32]) -> S {
+	let mut set = S::default();
+	for &t in items {
+		set.insert(t);
+	}
+	set
+}
+
+fn sorted<'a>(items: impl Iterator<Item = &'a u32>) -> Vec<u32> {
+	let mut items: Vec<u32> = items.copied().collect();
+	items.sort_unstable();
+	items
+}
+
+fn set_algebra<S>()
+where
+	S: SetAlgebra<u32> + SetMut<u32> + Default,
+	for<'a> S::ItemRef<'a>: Into<&'a u32>,
+{
+	let a: S = set_from(&[1, 2, 3]);
+	let b: S = set_from(&[3, 4]);
+
+	assert_eq!(sorted(a.union(&b).map(Into::into)), [1, 2, 3, 4]);
+	assert_eq!(sorted(a.intersection(&b).map(Into::into)), [3]);
+	assert_eq!(sorted(a.difference(&b).map(Into::into)), [1, 2]);
+	assert_eq!(sorted(a.symmetric_difference(&b).map(Into::into)), [1, 2, 4]);
+
+	assert!(set_from::<S>(&[1, 3]).is_subset(&a));
+	assert!(a.is_superset(&set_from(&[2])));
+	assert!(!a.is_subset(&b));
+	assert!(a.is_disjoint(&set_from(&[5, 6])));
+	assert!(!a.is_disjoint(&b));
+}
+
+fn set_algebra_mut<S>()
+where
+	S: SetAlgebraMut<u32> + Default,
+	for<'a> S::ItemRef<'a>: Into<&'a u32>,
+{
+	set_algebra::<S>();
+
+	let mut c: S = set_from(&[1, 2, 3]);
+	c.union_with(set_from(&[3, 4]));
+	assert_eq!(c.len(), 4);
+
+	c.retain_in(&set_from(&[2, 3, 4, 5]));
+	assert_eq!(c.len(), 3);
+	assert!(!c.contains(&1));
+
+	c.remove_in(&set_from(&[4]));
+	assert_eq!(c.len(), 2);
+	assert!(!c.contains(&4));
+
+	c.symmetric_difference_with(set_from(&[3, 6]));
+	assert_eq!(c.len(), 2);
+	assert!(c.contains(&2) && c.contains(&6));
+}
+
+/// Merges every set of `sets` into the first one.
+fn merge<S: UnionWith>(sets: Vec<S>) -> Option<S> {
+	let mut sets = sets.into_iter();
+	let mut result = sets.next()?;
+	for set in sets {
+		result.union_with(set)
+	}
+	Some(result)
+}
+
+#[test]
+fn btree_set_algebra() {
+	set_algebra_mut::<BTreeSet<u32>>()
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hash_set_algebra() {
+	set_algebra_mut::<std::collections::HashSet<u32>>()
+}
+
+#[cfg(all(feature = "std", feature = "hashbrown"))]
+#[test]
+fn hashbrown_set_algebra() {
+	use std::collections::hash_map::RandomState;
+	set_algebra_mut::<hashbrown::HashSet<u32, RandomState>>()
+}
+
+#[cfg(all(feature = "std", feature = "indexmap"))]
+#[test]
+fn index_set_algebra() {
+	use std::collections::hash_map::RandomState;
+	set_algebra_mut::<indexmap::IndexSet<u32, RandomState>>()
+}
+
+#[test]
+fn merge_sets() {
+	let sets = vec![BTreeSet::from([1, 2]), BTreeSet::from([2, 3]), BTreeSet::from([4])];
+	assert_eq!(merge(sets), Some(BTreeSet::from([1, 2, 3, 4])));
+	assert_eq!(merge(Vec::<BTreeSet<u32>>::new()), None);
+}
@@ -0,0 +1,79 @@
+use cc_traits::{Back, Get, GetMut, IntoItems, Iter, IterMut, Len, SortUnstable, Vec};
+
+fn sum<S: ?Sized + Len + Iter<Item = u32>>(s: &S) -> u32 {
+	s.iter().map(|t| *t).sum::<u32>() + s.len() as u32
+}
+
+fn double<S: ?Sized + GetMut<usize, Item = u32> + IterMut>(s: &mut S) {
+	for mut t in s.iter_mut() {
+		*t *= 2
+	}
+}
+
+#[cfg(feature = "alloc")]
+fn fill<B, I>(buffer: &mut B, items: I)
+where
+	B: cc_traits::PushBack + cc_traits::Clear,
+	I: IntoIterator<Item = B::Item>,
+{
+	buffer.clear();
+	for item in items {
+		buffer.push_back(item);
+	}
+}
+
+#[test]
+fn slices() {
+	let mut items = [1, 2, 3];
+	let slice: &mut [u32] = &mut items;
+	double(slice);
+	assert_eq!(sum(slice), 15);
+	assert_eq!(Get::get(slice, 3), None);
+	assert_eq!(Back::back(&slice).copied(), Some(6));
+}
+
+fn sorted<S: ?Sized + SortUnstable<Item = u32>>(s: &mut S) {
+	s.sort_unstable_by_key(|t| core::cmp::Reverse(*t));
+	s.sort_unstable()
+}
+
+#[test]
+fn sort_unstable() {
+	let mut items = [3, 1, 2];
+	sorted(&mut items);
+	assert_eq!(items, [1, 2, 3]);
+	sorted(&mut items[..]);
+	assert_eq!(items, [1, 2, 3]);
+}
+
+fn assert_vec<T, C: Vec<T>>(_: &C) {}
+
+#[test]
+fn arrays() {
+	let mut items = [1, 2, 3];
+	assert_vec(&items);
+	double(&mut items);
+	assert_eq!(sum(&items), 15);
+	assert_eq!(Len::len(&[0u32; 0]), 0);
+	assert_eq!(IntoItems::into_items(items).collect::<std::vec::Vec<_>>(), [2, 4, 6]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn strings() {
+	let mut buffer = String::from("previous");
+	fill(&mut buffer, "hello".chars());
+	assert_eq!(buffer, "hello");
+	assert_eq!(cc_traits::PopBack::pop_back(&mut buffer), Some('o'));
+
+	let mut bytes = std::vec::Vec::new();
+	fill(&mut bytes, *b"hello");
+	assert_eq!(bytes, b"hello");
+
+	#[cfg(feature = "smallvec")]
+	{
+		let mut bytes: smallvec::SmallVec<[u8; 8]> = smallvec::SmallVec::new();
+		fill(&mut bytes, *b"hello");
+		assert_eq!(bytes.as_slice(), b"hello");
+	}
+}
@@ -0,0 +1,63 @@
+#![cfg(feature = "alloc")]
+use cc_traits::{BinarySearch, Dedup, Reverse, Rotate, Sort, VecMut};
+use std::collections::VecDeque;
+
+fn normalize<V>(mut v: V) -> V
+where
+	V: VecMut<u32> + Sort + BinarySearch + Reverse + Rotate + Dedup,
+{
+	for t in [3, 1, 4, 1, 5, 9, 2, 6, 5, 3] {
+		v.push_back(t);
+	}
+
+	v.sort_unstable();
+	v.dedup();
+	assert_eq!(v.len(), 7);
+	assert_eq!(v.binary_search(&5), Ok(4));
+	assert_eq!(v.binary_search(&7), Err(6));
+	assert_eq!(v.binary_search_by_key(&18, |x| x * 2), Ok(6));
+
+	v.rotate_left(2);
+	v.rotate_right(1);
+	v.reverse();
+	v.sort_by_key(|x| x % 3);
+	v.dedup_by_key(|x| *x % 3);
+	v
+}
+
+#[test]
+fn vec() {
+	assert_eq!(normalize(Vec::new()), [9, 1, 5]);
+}
+
+#[test]
+fn vec_deque() {
+	let mut d = VecDeque::with_capacity(4);
+	d.push_front(9);
+	assert_eq!(normalize(d), [9, 1, 5]);
+}
+
+#[test]
+fn stable() {
+	let mut v = vec![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')];
+	Sort::sort_by(&mut v, |a, b| a.0.cmp(&b.0));
+	assert_eq!(v, [(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]);
+
+	let mut s = [3, 2, 1];
+	Sort::sort(&mut s[..]);
+	assert_eq!(s, [1, 2, 3]);
+}
+
+#[test]
+fn dedup_by() {
+	let mut d: VecDeque<_> = ["a", "A", "b", "B", "b", "c"].iter().map(|s| s.to_string()).collect();
+	Dedup::dedup_by(&mut d, |a, b| a.eq_ignore_ascii_case(b));
+	assert_eq!(d, ["a", "b", "c"]);
+}
+
+#[cfg(feature = "smallvec")]
+#[test]
+fn smallvec() {
+	let v = normalize(smallvec::SmallVec::<[u32; 4]>::new());
+	assert_eq!(v.as_slice(), [9, 1, 5]);
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "alloc")]
+use cc_traits::{
+	views::{Filtered, KeysView, Mapped, Projected, ValuesView},
+	Get, Iter, Len, Set,
+};
+use std::collections::BTreeMap;
+
+struct Point {
+	x: i32,
+	y: i32,
+}
+
+fn sorted<S: Set<u32> + Iter>(set: &S) -> Vec<u32> {
+	let mut items: Vec<_> = set.iter().map(|k| *k).collect();
+	items.sort_unstable();
+	items
+}
+
+#[test]
+fn projected() {
+	let points = vec![Point { x: 1, y: 2 }, Point { x: 3, y: 4 }];
+	let xs = Projected::new(&points, |p| &p.x);
+	assert_eq!(xs.len(), 2);
+	assert_eq!(*xs.get(1).unwrap(), 3);
+	assert!(xs.get(2).is_none());
+	assert_eq!(xs.iter().map(|x| *x).sum::<i32>(), 4);
+
+	let ys = Projected::new(points, |p| &p.y);
+	assert_eq!(ys.iter().map(|y| *y).collect::<Vec<_>>(), [2, 4]);
+	assert_eq!(ys.into_inner().len(), 2);
+}
+
+#[test]
+fn mapped() {
+	let points = vec![Point { x: 1, y: 2 }, Point { x: 3, y: 4 }];
+	let sums = Mapped::new(&points, |p| p.x + p.y);
+	assert_eq!(sums.len(), 2);
+	assert_eq!(*sums.get(1).unwrap(), 7);
+	assert!(sums.get(2).is_none());
+	assert_eq!(sums.iter().map(|s| *s).collect::<Vec<_>>(), [3, 7]);
+
+	let map = BTreeMap::from([(1, 'a'), (2, 'b')]);
+	let names = Mapped::new(ValuesView::new(map), |c| c.to_string());
+	assert_eq!(names.get(&2).unwrap().into_inner(), "b");
+	assert_eq!(names.iter().map(|s| s.into_inner()).collect::<Vec<_>>(), ["a", "b"]);
+	assert_eq!(names.into_inner().into_inner().len(), 2);
+}
+
+#[test]
+fn keys() {
+	let map = BTreeMap::from([(3, 'c'), (1, 'a'), (2, 'b')]);
+	let keys = KeysView::new(&map);
+	assert_eq!(keys.len(), 3);
+	assert!(keys.contains(&2));
+	assert_eq!(keys.get(&4), None);
+	assert_eq!(sorted(&keys), [1, 2, 3]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hash_map_keys() {
+	let map: std::collections::HashMap<_, _> = [(1, ()), (5, ())].iter().copied().collect();
+	assert_eq!(sorted(&KeysView::new(&map)), [1, 5]);
+}
+
+#[test]
+fn values() {
+	let map = BTreeMap::from([(1, "one".to_string()), (2, "two".to_string())]);
+	let values = ValuesView::new(map);
+	assert_eq!(values.len(), 2);
+	assert_eq!(values.get(&1).map(String::as_str), Some("one"));
+	assert_eq!(values.iter().cloned().collect::<Vec<_>>(), ["one", "two"]);
+}
+
+#[test]
+fn filtered() {
+	let v = vec![1, 2, 3, 4, 5, 6];
+	let even = Filtered::new(&v, |x| x % 2 == 0);
+	assert_eq!(even.iter().copied().collect::<Vec<_>>(), [2, 4, 6]);
+
+	let odd_keys = Filtered::new(KeysView::new(BTreeMap::from([(1, ()), (2, ())])), |k| k % 2 == 1);
+	assert_eq!(odd_keys.iter().count(), 1);
+}